@@ -19,6 +19,14 @@ use ouro_dag::fraud_detection::{
     FraudDetectionService, AlertThresholds, AlertSeverity,
 };
 
+/// Smallest units per OURO (10^12), matching `ouro_sdk::units::UNITS_PER_OURO`
+const UNITS_PER_OURO: u64 = 1_000_000_000_000;
+
+/// Units as a (display-only) OURO amount
+fn ouro(units: u64) -> f64 {
+    units as f64 / UNITS_PER_OURO as f64
+}
+
 fn main() {
     println!("\n{}", "=".repeat(80));
     println!("          OUROBOROS FRAUD DETECTION SYSTEM DEMO");
@@ -44,8 +52,8 @@ fn demo_cross_chain_fraud_detection() {
 
     // Setup: Relayer deposits bond
     println!("Step 1: Relayer deposits bond");
-    fraud_proofs.deposit_bond("honest_relayer".to_string(), 200_000_000);
-    fraud_proofs.deposit_bond("dishonest_relayer".to_string(), 200_000_000);
+    fraud_proofs.deposit_bond("honest_relayer".to_string(), 2 * UNITS_PER_OURO);
+    fraud_proofs.deposit_bond("dishonest_relayer".to_string(), 2 * UNITS_PER_OURO);
     println!("✅ Bonds deposited: 2 OURO each\n");
 
     // Scenario A: Honest relay
@@ -55,7 +63,7 @@ fn demo_cross_chain_fraud_detection() {
         destination_subchain: "eu_west".to_string(),
         sender: "alice".to_string(),
         recipient: "bob".to_string(),
-        amount: 5_000_000, // 0.05 OURO
+        amount: UNITS_PER_OURO / 20, // 0.05 OURO
         nonce: 1,
         timestamp: 1000,
     };
@@ -86,7 +94,7 @@ fn demo_cross_chain_fraud_detection() {
         destination_subchain: "eu_west".to_string(),
         sender: "charlie".to_string(),
         recipient: "dave".to_string(),
-        amount: 10_000_000_000, // 100 OURO (Charlie only has 1 OURO!)
+        amount: 100 * UNITS_PER_OURO, // 100 OURO (Charlie only has 1 OURO!)
         nonce: 1,
         timestamp: 2000,
     };
@@ -115,7 +123,7 @@ fn demo_cross_chain_fraud_detection() {
 
     // Verify fraud proof
     let mut source_state = HashMap::new();
-    source_state.insert("charlie".to_string(), 1_000_000); // Only 0.01 OURO
+    source_state.insert("charlie".to_string(), UNITS_PER_OURO / 100); // Only 0.01 OURO
 
     let is_fraud = fraud_proofs.verify_and_slash(
         hash2,
@@ -131,9 +139,9 @@ fn demo_cross_chain_fraud_detection() {
     }
 
     println!("📊 Summary:");
-    println!("  Honest relayer bond: {} OURO", fraud_proofs.get_relayer_bond("honest_relayer") / 100_000_000);
-    println!("  Dishonest relayer bond: {} OURO", fraud_proofs.get_relayer_bond("dishonest_relayer") / 100_000_000);
-    println!("  Dishonest relayer slashed: {} OURO", fraud_proofs.get_slashed_amount("dishonest_relayer") / 100_000_000);
+    println!("  Honest relayer bond: {} OURO", ouro(fraud_proofs.get_relayer_bond("honest_relayer")));
+    println!("  Dishonest relayer bond: {} OURO", ouro(fraud_proofs.get_relayer_bond("dishonest_relayer")));
+    println!("  Dishonest relayer slashed: {} OURO", ouro(fraud_proofs.get_slashed_amount("dishonest_relayer")));
 }
 
 /// Demo 2: Microchain Challenge System
@@ -145,7 +153,7 @@ fn demo_microchain_challenges() {
 
     // Setup: Operator deposits stake
     println!("Step 1: Operator deposits stake");
-    challenges.deposit_operator_stake("microchain_abc".to_string(), 1_000_000_000);
+    challenges.deposit_operator_stake("microchain_abc".to_string(), 10 * UNITS_PER_OURO);
     println!("✅ Stake deposited: 10 OURO\n");
 
     // Scenario A: Valid state anchor (no challenge)
@@ -172,7 +180,7 @@ fn demo_microchain_challenges() {
     println!("Scenario B: Invalid State Anchor - Challenged");
 
     // User deposits challenge bond
-    challenges.deposit_challenge_bond("concerned_user".to_string(), 20_000_000);
+    challenges.deposit_challenge_bond("concerned_user".to_string(), UNITS_PER_OURO / 5);
     println!("  💰 User deposits challenge bond: 0.2 OURO\n");
 
     let bad_anchor = challenges.submit_anchor(
@@ -225,7 +233,7 @@ fn demo_microchain_challenges() {
     let exit_id = challenges.request_force_exit(
         "microchain_abc".to_string(),
         "trapped_user".to_string(),
-        50_000_000, // 0.5 OURO
+        UNITS_PER_OURO / 2, // 0.5 OURO
         1,
         vec![[1u8; 32], [2u8; 32]], // Merkle proof
         [3u8; 32], // State root
@@ -239,7 +247,7 @@ fn demo_microchain_challenges() {
     let amount = challenges.process_force_exit(&exit_id, 3100).unwrap();
 
     println!("  ✅ Force exit completed!");
-    println!("  Amount withdrawn: {} OURO", amount / 100_000_000);
+    println!("  Amount withdrawn: {} OURO", ouro(amount));
 }
 
 /// Demo 3: Fraud Monitoring Service
@@ -249,7 +257,7 @@ fn demo_fraud_monitoring() {
 
     let thresholds = AlertThresholds {
         max_failure_rate: 0.2, // 20% for demo
-        max_volume_per_hour: 100_000_000_000,
+        max_volume_per_hour: 1_000 * UNITS_PER_OURO,
         max_rapid_transactions: 5, // Low threshold for demo
         min_anchor_frequency: 3600,
     };
@@ -261,7 +269,7 @@ fn demo_fraud_monitoring() {
     for i in 1..=5 {
         let alert = monitoring.monitor_relay(
             "good_relayer".to_string(),
-            1_000_000,
+            UNITS_PER_OURO / 100,
             true,
             1000 + i,
         );
@@ -278,7 +286,7 @@ fn demo_fraud_monitoring() {
         let success = i % 4 != 0; // 75% failure rate
         let alert = monitoring.monitor_relay(
             "bad_relayer".to_string(),
-            1_000_000,
+            UNITS_PER_OURO / 100,
             success,
             2000 + i,
         );
@@ -326,7 +334,7 @@ fn demo_fraud_monitoring() {
 
     let alert = monitoring.monitor_relay(
         "repeat_offender".to_string(),
-        1_000_000,
+        UNITS_PER_OURO / 100,
         true,
         4100,
     );
@@ -351,16 +359,16 @@ fn demo_admin_operations() {
     let monitoring = FraudDetectionService::new(AlertThresholds::default());
 
     // Simulate some activity
-    monitoring.monitor_relay("relayer_1".to_string(), 10_000_000, true, 1000);
-    monitoring.monitor_relay("relayer_1".to_string(), 20_000_000, true, 1001);
-    monitoring.monitor_relay("relayer_2".to_string(), 50_000_000, false, 1002);
+    monitoring.monitor_relay("relayer_1".to_string(), UNITS_PER_OURO / 10, true, 1000);
+    monitoring.monitor_relay("relayer_1".to_string(), UNITS_PER_OURO / 5, true, 1001);
+    monitoring.monitor_relay("relayer_2".to_string(), UNITS_PER_OURO / 2, false, 1002);
 
     println!("Admin Task 1: Check entity statistics");
     if let Some((total, successful, failed, volume)) = monitoring.get_activity_stats("relayer_1") {
         println!("  Entity: relayer_1");
         println!("  Total relays: {}", total);
         println!("  Success rate: {:.1}%", (successful as f64 / total as f64) * 100.0);
-        println!("  Total volume: {} OURO\n", volume / 100_000_000);
+        println!("  Total volume: {} OURO\n", ouro(volume));
     }
 
     println!("Admin Task 2: Review recent alerts");
@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ADDRESS_BOOK_FILE: &str = "midgard_addressbook.json";
+const ADDRESS_PREFIX: &str = "ouro1";
+
+/// Named recipients, stored next to the wallet file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AddressBook {
+    entries: BTreeMap<String, String>,
+}
+
+impl AddressBook {
+    /// Add or replace a named address
+    pub fn add(&mut self, name: &str, address: &str) -> Result<()> {
+        if name.starts_with(ADDRESS_PREFIX) {
+            return Err(anyhow!("Name '{}' looks like an address; pick another name", name));
+        }
+        if !address.starts_with(ADDRESS_PREFIX) {
+            return Err(anyhow!("Invalid address '{}': expected '{}' prefix", address, ADDRESS_PREFIX));
+        }
+
+        self.entries.insert(name.to_string(), address.to_string());
+        Ok(())
+    }
+
+    /// Remove a named address, returning it if it existed
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.entries.remove(name)
+    }
+
+    /// All entries, sorted by name
+    pub fn entries(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.entries.iter()
+    }
+
+    /// Resolve a saved name or pass a raw address through unchanged
+    pub fn resolve(&self, name_or_address: &str) -> Result<String> {
+        if let Some(address) = self.entries.get(name_or_address) {
+            return Ok(address.clone());
+        }
+        if name_or_address.starts_with(ADDRESS_PREFIX) {
+            return Ok(name_or_address.to_string());
+        }
+
+        Err(anyhow!(
+            "Unknown recipient '{}'. Add it with 'midgard-wallet addressbook add'",
+            name_or_address
+        ))
+    }
+
+    /// Load the address book from the home directory (empty if missing)
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::get_path()?)
+    }
+
+    /// Save the address book to the home directory
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::get_path()?)
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    fn save_to(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn get_path() -> Result<PathBuf> {
+        let home = dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find home directory"))?;
+        Ok(home.join(ADDRESS_BOOK_FILE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_list_and_resolve() {
+        let mut book = AddressBook::default();
+        book.add("bob", "ouro1bobaddress").unwrap();
+        book.add("alice", "ouro1aliceaddress").unwrap();
+
+        let names: Vec<_> = book.entries().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(book.resolve("bob").unwrap(), "ouro1bobaddress");
+        assert_eq!(book.resolve("ouro1rawaddress").unwrap(), "ouro1rawaddress");
+    }
+
+    #[test]
+    fn test_unknown_alias_is_rejected() {
+        let mut book = AddressBook::default();
+        book.add("bob", "ouro1bobaddress").unwrap();
+        assert_eq!(book.remove("bob").as_deref(), Some("ouro1bobaddress"));
+
+        assert!(book.resolve("bob").is_err());
+        assert!(book.resolve("carol").is_err());
+    }
+
+    #[test]
+    fn test_add_rejects_invalid_entries() {
+        let mut book = AddressBook::default();
+        assert!(book.add("bob", "not-an-address").is_err());
+        assert!(book.add("ouro1name", "ouro1bobaddress").is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("midgard_addressbook_{}.json", uuid::Uuid::new_v4()));
+
+        let mut book = AddressBook::default();
+        book.add("bob", "ouro1bobaddress").unwrap();
+        book.save_to(&path).unwrap();
+
+        let loaded = AddressBook::load_from(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.resolve("bob").unwrap(), "ouro1bobaddress");
+    }
+}
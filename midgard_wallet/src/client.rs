@@ -2,6 +2,9 @@ use anyhow::{anyhow, Result};
 use reqwest::blocking::Client;
 use serde::Deserialize;
 use serde_json::Value;
+use std::fmt;
+use std::thread;
+use std::time::{Duration, Instant};
 
 const DEFAULT_API_URL: &str = "http://localhost:8001";
 const DEFAULT_API_KEY: &str = "default_api_key";
@@ -26,6 +29,44 @@ pub struct NonceResponse {
     pub nonce: u64,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TxStatusResponse {
+    pub status: String,
+    #[serde(default)]
+    pub block_id: Option<String>,
+}
+
+/// Non-2xx response from the node, kept typed so pollers can tell transient statuses apart
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: reqwest::StatusCode,
+    pub message: String,
+}
+
+impl ApiError {
+    /// 408, 429 and 5xx may succeed on a later attempt; other statuses won't
+    fn is_transient(&self) -> bool {
+        matches!(self.status.as_u16(), 408 | 429) || self.status.is_server_error()
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API error {}: {}", self.status, self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Whether a failed request is worth retrying: transport failures and transient statuses
+fn is_transient(err: &anyhow::Error) -> bool {
+    if let Some(api_error) = err.downcast_ref::<ApiError>() {
+        return api_error.is_transient();
+    }
+    err.downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout() || e.is_request() || e.is_body())
+}
+
 pub struct OuroClient {
     client: Client,
     base_url: String,
@@ -120,6 +161,68 @@ impl OuroClient {
         Ok(response.status().is_success())
     }
 
+    /// Get the status of a submitted transaction (None if the node doesn't know it yet)
+    pub fn get_transaction_status(&self, tx_id: &str) -> Result<Option<TxStatusResponse>> {
+        let url = format!("{}/tx/{}", self.base_url, tx_id);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .map_err(|e| anyhow::Error::new(e).context("Failed to fetch transaction status"))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let message = response.text().unwrap_or_default();
+            return Err(ApiError { status, message }.into());
+        }
+
+        let status_response: TxStatusResponse = response
+            .json()
+            .map_err(|e| anyhow!("Failed to parse transaction status: {}", e))?;
+
+        Ok(Some(status_response))
+    }
+
+    /// Poll a transaction until it is included in a block, returning the block id
+    ///
+    /// Transient errors (network failures, 408, 429, 5xx) count as "still
+    /// pending". Any other error, a `failed` status or the deadline ends the wait.
+    pub fn wait_for_confirmation(
+        &self,
+        tx_id: &str,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<String> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match self.get_transaction_status(tx_id) {
+                Ok(Some(response)) => match response.status.as_str() {
+                    "confirmed" | "anchored" => {
+                        return Ok(response.block_id.unwrap_or_else(|| "unknown".to_string()));
+                    }
+                    "failed" => return Err(anyhow!("Transaction {} failed", tx_id)),
+                    _ => {}
+                },
+                Ok(None) => {}
+                Err(e) if is_transient(&e) => {}
+                Err(e) => return Err(e),
+            }
+
+            if Instant::now() + poll_interval > deadline {
+                return Err(anyhow!(
+                    "Transaction {} not confirmed after {}s",
+                    tx_id,
+                    timeout.as_secs()
+                ));
+            }
+            thread::sleep(poll_interval);
+        }
+    }
+
     /// Get nonce for an address
     pub fn get_nonce(&self, address: &str) -> Result<u64> {
         let url = format!("{}/ouro/nonce/{}", self.base_url, address);
@@ -142,3 +245,107 @@ impl OuroClient {
         Ok(nonce_response.nonce)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLL: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn test_wait_for_confirmation_polls_until_confirmed() {
+        let mut server = mockito::Server::new();
+        let pending = server.mock("GET", "/tx/tx123")
+            .with_body(r#"{"status":"pending"}"#)
+            .expect(2)
+            .create();
+        let confirmed = server.mock("GET", "/tx/tx123")
+            .with_body(r#"{"status":"confirmed","block_id":"block42"}"#)
+            .create();
+
+        let client = OuroClient::new(Some(server.url()));
+        let block_id = client
+            .wait_for_confirmation("tx123", Duration::from_secs(5), POLL)
+            .unwrap();
+
+        pending.assert();
+        confirmed.assert();
+        assert_eq!(block_id, "block42");
+    }
+
+    #[test]
+    fn test_wait_for_confirmation_survives_transient_errors() {
+        let mut server = mockito::Server::new();
+        let unavailable = server.mock("GET", "/tx/tx123")
+            .with_status(503)
+            .expect(1)
+            .create();
+        let confirmed = server.mock("GET", "/tx/tx123")
+            .with_body(r#"{"status":"confirmed","block_id":"block42"}"#)
+            .create();
+
+        let client = OuroClient::new(Some(server.url()));
+        let block_id = client
+            .wait_for_confirmation("tx123", Duration::from_secs(5), POLL)
+            .unwrap();
+
+        unavailable.assert();
+        confirmed.assert();
+        assert_eq!(block_id, "block42");
+    }
+
+    #[test]
+    fn test_wait_for_confirmation_fails_fast_on_client_error() {
+        let mut server = mockito::Server::new();
+        let bad_request = server.mock("GET", "/tx/tx123")
+            .with_status(400)
+            .with_body("invalid api key")
+            .expect(1)
+            .create();
+
+        let client = OuroClient::new(Some(server.url()));
+        let err = client
+            .wait_for_confirmation("tx123", Duration::from_secs(5), POLL)
+            .unwrap_err();
+
+        bad_request.assert();
+        assert!(err.to_string().contains("400"));
+    }
+
+    #[test]
+    fn test_connection_failure_is_transient() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let client = OuroClient::new(Some(format!("http://{}", addr)));
+        assert!(is_transient(&client.get_transaction_status("tx123").unwrap_err()));
+    }
+
+    #[test]
+    fn test_wait_for_confirmation_times_out() {
+        let mut server = mockito::Server::new();
+        server.mock("GET", "/tx/tx123")
+            .with_status(404)
+            .create();
+
+        let client = OuroClient::new(Some(server.url()));
+        let err = client
+            .wait_for_confirmation("tx123", Duration::from_millis(50), POLL)
+            .unwrap_err();
+        assert!(err.to_string().contains("not confirmed"));
+    }
+
+    #[test]
+    fn test_wait_for_confirmation_reports_failure() {
+        let mut server = mockito::Server::new();
+        server.mock("GET", "/tx/tx123")
+            .with_body(r#"{"status":"failed"}"#)
+            .create();
+
+        let client = OuroClient::new(Some(server.url()));
+        assert!(client
+            .wait_for_confirmation("tx123", Duration::from_secs(5), POLL)
+            .is_err());
+    }
+}
@@ -1,12 +1,18 @@
+mod addressbook;
 mod client;
 mod transaction;
 mod wallet;
 
-use anyhow::Result;
+use addressbook::AddressBook;
+use anyhow::{anyhow, Result};
 use clap::{Parser, Subcommand};
 use client::OuroClient;
 use colored::Colorize;
+use ouro_sdk::units::{format_ouro, parse_ouro};
 use transaction::Transaction;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
 use wallet::Wallet;
 
 #[derive(Parser)]
@@ -53,11 +59,15 @@ enum Commands {
 
     /// Send OURO tokens
     Send {
-        /// Recipient address
+        /// Recipient address or address book name
         to: String,
 
         /// Amount in smallest units (1 OURO = 1,000,000,000,000 units)
-        amount: u64,
+        amount: String,
+
+        /// Interpret the amount as OURO (e.g. 1.5) instead of smallest units
+        #[arg(long)]
+        ouro: bool,
 
         /// Transaction fee (default: 1000)
         #[arg(short, long, default_value_t = 1000)]
@@ -66,12 +76,113 @@ enum Commands {
         /// Transaction nonce (optional, will fetch from blockchain if not provided)
         #[arg(short, long)]
         nonce: Option<u64>,
+
+        /// Wait until the transaction is included in a block
+        #[arg(long)]
+        wait: bool,
+
+        /// Seconds to wait for confirmation with --wait
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+
+    /// Build and sign a transaction offline without submitting it
+    Sign {
+        /// Recipient address or address book name
+        to: String,
+
+        /// Amount in smallest units (1 OURO = 1,000,000,000,000 units)
+        amount: String,
+
+        /// Interpret the amount as OURO (e.g. 1.5) instead of smallest units
+        #[arg(long)]
+        ouro: bool,
+
+        /// Transaction fee (default: 1000)
+        #[arg(short, long, default_value_t = 1000)]
+        fee: u64,
+
+        /// Transaction nonce (required, the node is not contacted)
+        #[arg(short, long)]
+        nonce: u64,
+
+        /// Write the signed transaction to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Submit a transaction signed with the sign command
+    Broadcast {
+        /// Signed transaction file
+        file: PathBuf,
+    },
+
+    /// Manage named recipients
+    Addressbook {
+        #[command(subcommand)]
+        action: AddressbookAction,
+    },
+
+    /// Manage accounts derived from the wallet mnemonic
+    Account {
+        #[command(subcommand)]
+        action: AccountAction,
     },
 
     /// Show blockchain status
     Status,
 }
 
+#[derive(Subcommand)]
+enum AddressbookAction {
+    /// Save an address under a name
+    Add {
+        /// Name to use in place of the address
+        name: String,
+
+        /// Recipient address
+        address: String,
+    },
+
+    /// Remove a saved name
+    Remove {
+        /// Saved name
+        name: String,
+    },
+
+    /// List saved names
+    List,
+}
+
+#[derive(Subcommand)]
+enum AccountAction {
+    /// Derive the next account from the mnemonic
+    Add,
+
+    /// Use an account for balance and send
+    Select {
+        /// Account index
+        index: u32,
+    },
+
+    /// List derived accounts
+    List,
+}
+
+/// How often `send --wait` polls the node for the transaction status
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Parse an amount argument given in units, or in OURO when `ouro` is set
+fn resolve_amount(amount: &str, ouro: bool) -> Result<u64> {
+    if ouro {
+        Ok(parse_ouro(amount)?)
+    } else {
+        amount
+            .parse::<u64>()
+            .map_err(|_| anyhow!("Invalid amount in units: {}", amount))
+    }
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
     let client = OuroClient::new(Some(cli.node_url.clone()));
@@ -129,6 +240,7 @@ fn main() -> Result<()> {
             println!("\n{}", "👛 Wallet Information".cyan().bold());
             println!("{}", "═".repeat(50).cyan());
             println!("{}: {}", "Name".bright_white(), wallet.name);
+            println!("{}: {}", "Account".bright_white(), wallet.selected_account);
             println!("{}: {}", "Address".bright_white(), wallet.address.green());
             println!("{}: {}", "Public Key".bright_white(), wallet.public_key);
             println!("{}: {}", "Created".bright_white(), wallet.created_at);
@@ -140,8 +252,7 @@ fn main() -> Result<()> {
 
             match client.get_balance(&wallet.address) {
                 Ok(balance) => {
-                    let ouro_balance = balance as f64 / 1_000_000_000_000.0;
-                    println!("\n{}", format!("Balance: {} OURO", ouro_balance).green().bold());
+                    println!("\n{}", format!("Balance: {} OURO", format_ouro(balance)).green().bold());
                     println!("{}", format!("({} units)", balance).bright_black());
                 }
                 Err(e) => {
@@ -154,10 +265,15 @@ fn main() -> Result<()> {
         Commands::Send {
             to,
             amount,
+            ouro,
             fee,
             nonce,
+            wait,
+            timeout,
         } => {
             let wallet = Wallet::load()?;
+            let amount = resolve_amount(&amount, ouro)?;
+            let to = AddressBook::load()?.resolve(&to)?;
             println!("{}", "📤 Preparing transaction...".cyan());
 
             // Fetch nonce from blockchain if not provided
@@ -200,7 +316,7 @@ fn main() -> Result<()> {
             println!(
                 "{}: {} OURO",
                 "Amount".bright_white(),
-                amount as f64 / 1_000_000_000_000.0
+                format_ouro(amount)
             );
             println!("{}: {}", "Fee".bright_white(), fee);
             println!("{}: {}", "Nonce".bright_white(), tx_nonce);
@@ -209,6 +325,77 @@ fn main() -> Result<()> {
 
             // Submit transaction
             println!("\n{}", "📡 Submitting transaction...".cyan());
+            match client.submit_transaction(tx.to_api_format()) {
+                Ok(tx_id) => {
+                    println!("\n{}", "✅ Transaction submitted successfully!".green().bold());
+                    println!("{}: {}", "Transaction ID".bright_white(), tx_id.cyan());
+
+                    if wait {
+                        println!("\n{}", "⏳ Waiting for confirmation...".cyan());
+                        match client.wait_for_confirmation(
+                            &tx_id,
+                            Duration::from_secs(timeout),
+                            CONFIRMATION_POLL_INTERVAL,
+                        ) {
+                            Ok(block_id) => {
+                                println!("{}", "✅ Transaction confirmed!".green().bold());
+                                println!("{}: {}", "Block ID".bright_white(), block_id.cyan());
+                            }
+                            Err(e) => {
+                                println!("{}", format!("⚠️  {}", e).yellow());
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    println!("{}", format!("❌ Transaction failed: {}", e).red());
+                }
+            }
+        }
+
+        Commands::Sign {
+            to,
+            amount,
+            ouro,
+            fee,
+            nonce,
+            output,
+        } => {
+            let wallet = Wallet::load()?;
+            let amount = resolve_amount(&amount, ouro)?;
+            let to = AddressBook::load()?.resolve(&to)?;
+
+            let mut tx = Transaction::new(
+                wallet.address.clone(),
+                to,
+                amount,
+                fee,
+                nonce,
+                wallet.public_key.clone(),
+            );
+            tx.sign(&wallet.get_signing_key()?)?;
+
+            let json = serde_json::to_string_pretty(&tx)?;
+            match output {
+                Some(path) => {
+                    fs::write(&path, json)?;
+                    eprintln!("{}", format!("✅ Signed transaction written to {}", path.display()).green());
+                }
+                None => println!("{}", json),
+            }
+        }
+
+        Commands::Broadcast { file } => {
+            let json = fs::read_to_string(&file)?;
+            let tx: Transaction = serde_json::from_str(&json)
+                .map_err(|e| anyhow!("Invalid signed transaction file: {}", e))?;
+
+            if !tx.verify()? {
+                println!("{}", "❌ Signature does not match the transaction contents".red());
+                return Ok(());
+            }
+
+            println!("{}", "📡 Broadcasting signed transaction...".cyan());
             match client.submit_transaction(tx.to_api_format()) {
                 Ok(tx_id) => {
                     println!("\n{}", "✅ Transaction submitted successfully!".green().bold());
@@ -220,6 +407,61 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Addressbook { action } => {
+            let mut book = AddressBook::load()?;
+
+            match action {
+                AddressbookAction::Add { name, address } => {
+                    book.add(&name, &address)?;
+                    book.save()?;
+                    println!("{}", format!("✅ Saved {} → {}", name, address).green());
+                }
+                AddressbookAction::Remove { name } => match book.remove(&name) {
+                    Some(_) => {
+                        book.save()?;
+                        println!("{}", format!("✅ Removed {}", name).green());
+                    }
+                    None => println!("{}", format!("⚠️  No entry named {}", name).yellow()),
+                },
+                AddressbookAction::List => {
+                    println!("\n{}", "📒 Address Book".cyan().bold());
+                    println!("{}", "═".repeat(50).cyan());
+                    for (name, address) in book.entries() {
+                        println!("{}: {}", name.bright_white(), address.green());
+                    }
+                }
+            }
+        }
+
+        Commands::Account { action } => {
+            let mut wallet = Wallet::load()?;
+
+            match action {
+                AccountAction::Add => {
+                    let account = wallet.add_account()?;
+                    wallet.save()?;
+                    println!("{}", format!("✅ Added account {}", account.index).green());
+                    println!("{}", format!("Address: {}", account.address).cyan());
+                }
+                AccountAction::Select { index } => {
+                    wallet.select_account(index)?;
+                    wallet.save()?;
+                    println!("{}", format!("✅ Selected account {} ({})", index, wallet.address).green());
+                }
+                AccountAction::List => {
+                    println!("\n{}", "🗂️  Accounts".cyan().bold());
+                    println!("{}", "═".repeat(50).cyan());
+                    if wallet.accounts.is_empty() {
+                        println!("{}: {}", "0 *".bright_white(), wallet.address.green());
+                    }
+                    for account in &wallet.accounts {
+                        let marker = if account.index == wallet.selected_account { " *" } else { "" };
+                        println!("{}: {}", format!("{}{}", account.index, marker).bright_white(), account.address.green());
+                    }
+                }
+            }
+        }
+
         Commands::Status => {
             println!("{}", "🔍 Checking node status...".cyan());
 
@@ -241,3 +483,22 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same fixture the SDK and the wallet UI test against
+    const UNITS_CASES: &str = include_str!("../../ouro_sdk/testdata/units_cases.json");
+
+    #[test]
+    fn test_ouro_amounts_match_shared_table() {
+        let cases: Vec<(String, u64)> = serde_json::from_str(UNITS_CASES).unwrap();
+        for (human, units) in cases {
+            assert_eq!(resolve_amount(&human, true).unwrap(), units);
+            assert_eq!(resolve_amount(&units.to_string(), false).unwrap(), units);
+            assert_eq!(format_ouro(units), human);
+        }
+        assert!(resolve_amount("1.5", false).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use ed25519_dalek::{Signer, SigningKey};
+use ouro_sdk::SigningMessage;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -47,68 +48,140 @@ impl Transaction {
         }
     }
 
+    /// Signed fields, laid out by the SDK's shared builder
+    fn signing_fields(&self) -> SigningMessage<'_> {
+        SigningMessage {
+            chain_id: &self.chain_id,
+            nonce: self.nonce,
+            id: &self.id,
+            sender: &self.sender,
+            recipient: &self.recipient,
+            amount: self.amount,
+            fee: self.fee,
+            timestamp: self.timestamp.timestamp(),
+            parents: &self.parents,
+            payload: self.payload.as_deref(),
+        }
+    }
+
     /// Build signing message (must match blockchain's signing logic)
     fn signing_message(&self) -> Vec<u8> {
-        let mut msg = Vec::new();
-
-        // Chain ID
-        msg.extend_from_slice(self.chain_id.as_bytes());
-
-        // Nonce
-        msg.extend_from_slice(&self.nonce.to_le_bytes());
-
-        // Transaction ID
-        msg.extend_from_slice(self.id.as_bytes());
+        self.signing_fields().to_bytes()
+    }
 
-        // Sender
-        msg.extend_from_slice(self.sender.as_bytes());
+    /// Sign the transaction with private key
+    pub fn sign(&mut self, signing_key: &SigningKey) -> Result<()> {
+        let message = self.signing_message();
+        let signature = signing_key.sign(&message);
+        self.signature = hex::encode(signature.to_bytes());
+        Ok(())
+    }
 
-        // Recipient
-        msg.extend_from_slice(self.recipient.as_bytes());
+    /// Verify the signature against the embedded public key
+    pub fn verify(&self) -> Result<bool> {
+        Ok(self.signing_fields().verify(&self.public_key, &self.signature)?)
+    }
 
-        // Amount
-        msg.extend_from_slice(&self.amount.to_le_bytes());
+    /// Convert transaction to API submission format (the SDK's `/tx/submit` body)
+    pub fn to_api_format(&self) -> serde_json::Value {
+        self.signing_fields().submit_body(&self.public_key, &self.signature)
+    }
+}
 
-        // Fee
-        msg.extend_from_slice(&self.fee.to_le_bytes());
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signature, VerifyingKey};
+
+    fn signed_tx() -> Transaction {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut tx = Transaction::new(
+            "ouro1sender".to_string(),
+            "ouro1recipient".to_string(),
+            1_000,
+            10,
+            3,
+            hex::encode(signing_key.verifying_key().to_bytes()),
+        );
+        tx.sign(&signing_key).unwrap();
+        tx
+    }
 
-        // Timestamp
-        msg.extend_from_slice(&self.timestamp.timestamp().to_le_bytes());
+    #[test]
+    fn test_offline_signed_payload_verifies() {
+        let json = serde_json::to_string_pretty(&signed_tx()).unwrap();
 
-        // Parents (DAG)
-        for parent in &self.parents {
-            msg.extend_from_slice(parent.as_bytes());
-        }
+        // Broadcast side only sees the exported JSON
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["nonce"], 3);
+        assert_eq!(value["chain_id"], "ouroboros-mainnet-1");
+        assert!(value["public_key"].as_str().is_some_and(|k| !k.is_empty()));
 
-        // Payload (if any)
-        if let Some(ref payload) = self.payload {
-            msg.extend_from_slice(payload.as_bytes());
-        }
+        let tx: Transaction = serde_json::from_value(value).unwrap();
+        assert!(tx.verify().unwrap());
+    }
 
-        msg
+    /// The same logical transaction signs to the same bytes here and in the SDK
+    #[test]
+    fn test_signing_message_matches_sdk() {
+        let mut tx = signed_tx();
+        tx.parents = vec!["p1".to_string(), "p2".to_string()];
+        tx.payload = Some(r#"{"method":"transfer"}"#.to_string());
+
+        let mut sdk_tx = ouro_sdk::Transaction::new(tx.sender.clone(), tx.recipient.clone(), tx.amount)
+            .with_nonce(tx.nonce)
+            .with_fee(tx.fee)
+            .with_parents(tx.parents.clone())
+            .with_data(serde_json::json!({"method": "transfer"}));
+        sdk_tx.id = tx.id.clone();
+        sdk_tx.timestamp = Some(tx.timestamp.to_rfc3339());
+
+        assert_eq!(tx.signing_message(), sdk_tx.signing_message().unwrap());
     }
 
-    /// Sign the transaction with private key
-    pub fn sign(&mut self, signing_key: &SigningKey) -> Result<()> {
-        let message = self.signing_message();
-        let signature = signing_key.sign(&message);
-        self.signature = hex::encode(signature.to_bytes());
-        Ok(())
+    /// Rebuild the signing message from nothing but the submitted JSON
+    #[test]
+    fn test_api_format_carries_every_signed_field() {
+        let mut tx = signed_tx();
+        tx.timestamp -= chrono::Duration::hours(6); // signed offline, broadcast later
+        tx.parents = vec!["p1".to_string()];
+        tx.payload = Some("memo".to_string());
+        tx.sign(&SigningKey::from_bytes(&[7u8; 32])).unwrap();
+
+        let body = tx.to_api_format();
+        let parents: Vec<String> = serde_json::from_value(body["parents"].clone()).unwrap();
+        let message = SigningMessage {
+            chain_id: body["chain_id"].as_str().unwrap(),
+            nonce: body["nonce"].as_u64().unwrap(),
+            id: body["tx_hash"].as_str().unwrap(),
+            sender: body["sender"].as_str().unwrap(),
+            recipient: body["recipient"].as_str().unwrap(),
+            amount: body["payload"]["amount"].as_u64().unwrap(),
+            fee: body["payload"]["fee"].as_u64().unwrap(),
+            timestamp: body["timestamp"].as_i64().unwrap(),
+            parents: &parents,
+            payload: body["data"].as_str(),
+        }
+        .to_bytes();
+
+        let public_key: [u8; 32] = hex::decode(body["payload"]["public_key"].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let signature: [u8; 64] = hex::decode(body["signature"].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        VerifyingKey::from_bytes(&public_key)
+            .unwrap()
+            .verify_strict(&message, &Signature::from_bytes(&signature))
+            .unwrap();
     }
 
-    /// Convert transaction to API submission format
-    pub fn to_api_format(&self) -> serde_json::Value {
-        serde_json::json!({
-            "tx_hash": self.id,
-            "sender": self.sender,
-            "recipient": self.recipient,
-            "signature": self.signature,
-            "payload": {
-                "amount": self.amount,
-                "fee": self.fee,
-                "public_key": self.public_key
-            },
-            "nonce": self.nonce
-        })
+    #[test]
+    fn test_tampered_payload_fails_verification() {
+        let mut tx = signed_tx();
+        tx.amount += 1;
+        assert!(!tx.verify().unwrap());
     }
 }
@@ -2,13 +2,33 @@ use anyhow::{anyhow, Result};
 use bech32::{Bech32, Hrp};
 use bip39::{Language, Mnemonic};
 use ed25519_dalek::{SigningKey, VerifyingKey, SECRET_KEY_LENGTH};
+use hmac::{Hmac, Mac};
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use sha2::Sha512;
 use std::fs;
 use std::path::PathBuf;
 
 const WALLET_FILE: &str = "midgard_wallet.json";
 
+/// SLIP-0044 style coin type used in account derivation paths
+const COIN_TYPE: u32 = 1919;
+
+/// Offset marking a hardened derivation index
+const HARDENED: u32 = 0x8000_0000;
+
+/// An account derived from the wallet's mnemonic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub index: u32,
+    pub address: String,
+    pub public_key: String,
+}
+
+/// Wallet file contents
+///
+/// `address`, `public_key` and `private_key` always hold the selected account,
+/// so balance and send operate on it without knowing about accounts.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Wallet {
     pub name: String,
@@ -17,6 +37,14 @@ pub struct Wallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub private_key: Option<String>,
     pub created_at: String,
+    /// BIP39 seed (hex), present for mnemonic-based wallets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<String>,
+    /// Accounts derived so far (index 0 is the original wallet key)
+    #[serde(default)]
+    pub accounts: Vec<Account>,
+    #[serde(default)]
+    pub selected_account: u32,
 }
 
 impl Wallet {
@@ -28,28 +56,8 @@ impl Wallet {
 
         let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
             .map_err(|e| anyhow!("Failed to generate mnemonic: {}", e))?;
-        let seed = mnemonic.to_seed("");
-
-        // Use first 32 bytes as Ed25519 private key
-        let private_key_bytes: [u8; SECRET_KEY_LENGTH] = seed[..SECRET_KEY_LENGTH]
-            .try_into()
-            .map_err(|_| anyhow!("Failed to generate private key"))?;
-
-        let signing_key = SigningKey::from_bytes(&private_key_bytes);
-        let verifying_key = signing_key.verifying_key();
-
-        let address = Self::encode_address(&verifying_key)?;
-        let public_key = hex::encode(verifying_key.to_bytes());
-        let private_key = hex::encode(signing_key.to_keypair_bytes());
-
-        let wallet = Wallet {
-            name,
-            address,
-            public_key,
-            private_key: Some(private_key),
-            created_at: chrono::Utc::now().to_rfc3339(),
-        };
 
+        let wallet = Self::from_seed(&mnemonic.to_seed(""), name)?;
         Ok((wallet, mnemonic.to_string()))
     }
 
@@ -58,12 +66,12 @@ impl Wallet {
         let mnemonic = Mnemonic::parse_in_normalized(Language::English, mnemonic_phrase)
             .map_err(|e| anyhow!("Invalid mnemonic: {}", e))?;
 
-        let seed = mnemonic.to_seed("");
-        let private_key_bytes: [u8; SECRET_KEY_LENGTH] = seed[..SECRET_KEY_LENGTH]
-            .try_into()
-            .map_err(|_| anyhow!("Failed to generate private key"))?;
+        Self::from_seed(&mnemonic.to_seed(""), name)
+    }
 
-        let signing_key = SigningKey::from_bytes(&private_key_bytes);
+    /// Build a wallet from a BIP39 seed with account 0 selected
+    fn from_seed(seed: &[u8], name: String) -> Result<Self> {
+        let signing_key = Self::derive_account_key(seed, 0)?;
         let verifying_key = signing_key.verifying_key();
 
         let address = Self::encode_address(&verifying_key)?;
@@ -72,13 +80,87 @@ impl Wallet {
 
         Ok(Wallet {
             name,
-            address,
-            public_key,
+            address: address.clone(),
+            public_key: public_key.clone(),
             private_key: Some(private_key),
             created_at: chrono::Utc::now().to_rfc3339(),
+            seed: Some(hex::encode(seed)),
+            accounts: vec![Account { index: 0, address, public_key }],
+            selected_account: 0,
         })
     }
 
+    /// Derive the signing key for an account index
+    ///
+    /// Account 0 is the first 32 bytes of the seed, which is how wallets were
+    /// derived before multi-account support, so existing addresses are kept.
+    /// Further accounts follow SLIP-0010 at m/44'/COIN_TYPE'/index'/0'/0'.
+    fn derive_account_key(seed: &[u8], index: u32) -> Result<SigningKey> {
+        if index == 0 {
+            let private_key_bytes: [u8; SECRET_KEY_LENGTH] = seed
+                .get(..SECRET_KEY_LENGTH)
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| anyhow!("Failed to generate private key"))?;
+            return Ok(SigningKey::from_bytes(&private_key_bytes));
+        }
+        if index >= HARDENED {
+            return Err(anyhow!("Account index {} out of range", index));
+        }
+
+        let path = [44, COIN_TYPE, index, 0, 0];
+        Ok(SigningKey::from_bytes(&slip10_derive(seed, &path)))
+    }
+
+    /// Derive the next account from the wallet's seed
+    pub fn add_account(&mut self) -> Result<Account> {
+        let seed = self.seed_bytes()?;
+        let index = self.accounts.iter().map(|a| a.index + 1).max().unwrap_or(1);
+
+        let verifying_key = Self::derive_account_key(&seed, index)?.verifying_key();
+        let account = Account {
+            index,
+            address: Self::encode_address(&verifying_key)?,
+            public_key: hex::encode(verifying_key.to_bytes()),
+        };
+
+        self.accounts.push(account.clone());
+        Ok(account)
+    }
+
+    /// Make a previously added account the one used by balance/send
+    pub fn select_account(&mut self, index: u32) -> Result<()> {
+        if !self.accounts.iter().any(|a| a.index == index) {
+            return Err(anyhow!("Account {} not found. Add it with 'midgard-wallet account add'", index));
+        }
+
+        let signing_key = Self::derive_account_key(&self.seed_bytes()?, index)?;
+        let verifying_key = signing_key.verifying_key();
+
+        self.address = Self::encode_address(&verifying_key)?;
+        self.public_key = hex::encode(verifying_key.to_bytes());
+        self.private_key = Some(hex::encode(signing_key.to_keypair_bytes()));
+        self.selected_account = index;
+        Ok(())
+    }
+
+    fn seed_bytes(&self) -> Result<Vec<u8>> {
+        let seed = match (&self.seed, self.accounts.is_empty()) {
+            (Some(seed), _) => seed,
+            // Saved before accounts existed: the seed was never stored
+            (None, true) => {
+                return Err(anyhow!(
+                    "This wallet file predates multiple accounts and has no stored seed. \
+                     If it came from a mnemonic, re-import it with 'midgard-wallet import --mnemonic' \
+                     (account 0 keeps the same address)"
+                ))
+            }
+            (None, false) => {
+                return Err(anyhow!("Multiple accounts require a wallet created or imported from a mnemonic"))
+            }
+        };
+        hex::decode(seed).map_err(|_| anyhow!("Invalid seed in wallet file"))
+    }
+
     /// Import wallet from private key hex
     pub fn from_private_key(private_key_hex: &str, name: String) -> Result<Self> {
         let key_bytes = hex::decode(private_key_hex)
@@ -98,10 +180,13 @@ impl Wallet {
 
         Ok(Wallet {
             name,
-            address,
-            public_key,
+            address: address.clone(),
+            public_key: public_key.clone(),
             private_key: Some(private_key_hex.to_string()),
             created_at: chrono::Utc::now().to_rfc3339(),
+            seed: None,
+            accounts: vec![Account { index: 0, address, public_key }],
+            selected_account: 0,
         })
     }
 
@@ -173,3 +258,111 @@ impl Wallet {
             .unwrap_or(false)
     }
 }
+
+/// SLIP-0010 ed25519 derivation (every path element is hardened)
+fn slip10_derive(seed: &[u8], path: &[u32]) -> [u8; 32] {
+    let split = |bytes: &[u8]| -> ([u8; 32], [u8; 32]) {
+        (bytes[..32].try_into().unwrap(), bytes[32..].try_into().unwrap())
+    };
+
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"ed25519 seed").expect("HMAC accepts any key length");
+    mac.update(seed);
+    let (mut key, mut chain_code) = split(&mac.finalize().into_bytes());
+
+    for index in path {
+        let mut mac = Hmac::<Sha512>::new_from_slice(&chain_code).expect("HMAC accepts any key length");
+        mac.update(&[0]);
+        mac.update(&key);
+        mac.update(&(index | HARDENED).to_be_bytes());
+        (key, chain_code) = split(&mac.finalize().into_bytes());
+    }
+
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_slip10_test_vector() {
+        // SLIP-0010 ed25519 test vector 1, chain m/0'
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        assert_eq!(
+            hex::encode(slip10_derive(&seed, &[])),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex::encode(slip10_derive(&seed, &[0])),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+    }
+
+    #[test]
+    fn test_accounts_are_distinct_and_deterministic() {
+        let mut first = Wallet::from_mnemonic(MNEMONIC, "a".to_string()).unwrap();
+        let mut second = Wallet::from_mnemonic(MNEMONIC, "b".to_string()).unwrap();
+
+        let account1 = first.add_account().unwrap();
+        assert_eq!(account1.index, 1);
+        assert_eq!(second.add_account().unwrap().address, account1.address);
+        assert_ne!(first.accounts[0].address, account1.address);
+    }
+
+    #[test]
+    fn test_account_zero_keeps_original_address() {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, MNEMONIC).unwrap();
+        let seed = mnemonic.to_seed("");
+        let legacy_key = SigningKey::from_bytes(&seed[..SECRET_KEY_LENGTH].try_into().unwrap());
+
+        let wallet = Wallet::from_mnemonic(MNEMONIC, "a".to_string()).unwrap();
+        assert_eq!(wallet.address, Wallet::encode_address(&legacy_key.verifying_key()).unwrap());
+    }
+
+    #[test]
+    fn test_select_account_switches_keys() {
+        let mut wallet = Wallet::from_mnemonic(MNEMONIC, "a".to_string()).unwrap();
+        let original = wallet.address.clone();
+        let account1 = wallet.add_account().unwrap();
+
+        wallet.select_account(1).unwrap();
+        assert_eq!(wallet.address, account1.address);
+        assert_eq!(
+            hex::encode(wallet.get_signing_key().unwrap().verifying_key().to_bytes()),
+            account1.public_key
+        );
+
+        wallet.select_account(0).unwrap();
+        assert_eq!(wallet.address, original);
+        assert!(wallet.select_account(5).is_err());
+    }
+
+    #[test]
+    fn test_private_key_wallet_cannot_add_accounts() {
+        let key = SigningKey::from_bytes(&[9u8; 32]);
+        let mut wallet =
+            Wallet::from_private_key(&hex::encode(key.to_keypair_bytes()), "k".to_string()).unwrap();
+        let err = wallet.add_account().unwrap_err();
+        assert!(err.to_string().contains("require a wallet created or imported from a mnemonic"));
+    }
+
+    #[test]
+    fn test_legacy_wallet_is_told_to_reimport() {
+        // Wallet file written before `seed` and `accounts` were added
+        let legacy = Wallet::from_mnemonic(MNEMONIC, "old".to_string()).unwrap();
+        let json = serde_json::json!({
+            "name": legacy.name,
+            "address": legacy.address,
+            "public_key": legacy.public_key,
+            "private_key": legacy.private_key,
+            "created_at": legacy.created_at,
+        });
+
+        let mut wallet: Wallet = serde_json::from_value(json).unwrap();
+        let err = wallet.add_account().unwrap_err();
+        assert!(err.to_string().contains("re-import"));
+    }
+}
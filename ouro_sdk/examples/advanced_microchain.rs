@@ -1,4 +1,4 @@
-use ouro_sdk::{Microchain, MicrochainConfig, ConsensusType, AnchorFrequency, Transaction};
+use ouro_sdk::{Microchain, MicrochainConfig, ConsensusType, AnchorFrequency};
 use serde_json::json;
 
 #[tokio::main]
@@ -17,7 +17,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 2. Build custom transaction with data payload
     println!("🔨 Building custom transaction with data...");
-    let mut tx = microchain.tx()
+    let tx = microchain.tx()
         .from("ouro1alice...")
         .to("ouro1smartcontract...")
         .amount(500)
@@ -1,4 +1,4 @@
-use ouro_sdk::{Microchain, MicrochainConfig, ConsensusType, AnchorFrequency, Transaction};
+use ouro_sdk::{Microchain, MicrochainConfig};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
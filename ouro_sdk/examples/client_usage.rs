@@ -51,8 +51,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 6. Submit mainchain transaction
     println!("📤 Submitting mainchain transaction...");
     let mut tx = Transaction::new("ouro1alice...", "ouro1bob...", 1000);
-    // In production: tx.sign_with_key("private_key")?;
-    tx.signature = "mock_signature".to_string();  // For demo only
+    // Demo key only - load your real key from secure storage
+    tx.sign_with_key("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")?;
 
     match client.submit_transaction(&tx).await {
         Ok(tx_id) => println!("✅ Transaction submitted: {}\n", tx_id),
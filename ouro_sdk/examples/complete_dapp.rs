@@ -1,8 +1,13 @@
 use ouro_sdk::{
-    MicrochainBuilder, ConsensusType, AnchorFrequency, Transaction
+    MicrochainBuilder, ConsensusType, AnchorFrequency
 };
 use serde_json::json;
 
+// Demo signing keys (hex Ed25519 secrets) - never use these outside local testing
+const SELLER_KEY: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+const BUYER_KEY: &str = "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+const CONTRACT_KEY: &str = "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7";
+
 /// Complete dApp Example: A decentralized marketplace
 ///
 /// This example demonstrates a full workflow for building a dApp on Ouroboros:
@@ -15,12 +20,12 @@ use serde_json::json;
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🏪 Ouroboros SDK - Complete dApp Example: Marketplace\n");
-    println!("=" .repeat(60));
+    println!("{}", "=".repeat(60));
     println!();
 
     // Step 1: Deploy the marketplace microchain
     println!("📦 Step 1: Deploying Marketplace Microchain");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let mut marketplace = MicrochainBuilder::new("DecentralizedMarket", "ouro1marketplace_owner...")
         .node("http://localhost:8001")
@@ -38,7 +43,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 2: Setup - Create initial liquidity
     println!("💰 Step 2: Initial Setup - Creating Liquidity");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let seller = "ouro1seller_alice...";
     let buyer = "ouro1buyer_bob...";
@@ -54,7 +59,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 3: Seller lists an item
     println!("📝 Step 3: Seller Lists Item for Sale");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let mut list_tx = marketplace.tx()
         .from(seller)
@@ -76,9 +81,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }))
         .build()?;
 
-    // In production, sign with seller's private key
-    // list_tx.sign_with_key("seller_private_key")?;
-    list_tx.signature = "mock_seller_signature".to_string();
+    // Sign with seller's private key
+    list_tx.sign_with_key(SELLER_KEY)?;
 
     let list_tx_id = marketplace.submit_tx(&list_tx).await?;
     println!("✅ Item listed!");
@@ -89,7 +93,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 4: Buyer purchases the item
     println!("🛒 Step 4: Buyer Purchases Item");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let mut purchase_tx = marketplace.tx()
         .from(buyer)
@@ -104,8 +108,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build()?;
 
     // Sign with buyer's key
-    // purchase_tx.sign_with_key("buyer_private_key")?;
-    purchase_tx.signature = "mock_buyer_signature".to_string();
+    purchase_tx.sign_with_key(BUYER_KEY)?;
 
     let purchase_tx_id = marketplace.submit_tx(&purchase_tx).await?;
     println!("✅ Purchase successful!");
@@ -116,7 +119,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 5: Transfer item ownership
     println!("🔄 Step 5: Transfer Item Ownership");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let mut transfer_tx = marketplace.tx()
         .from(marketplace_contract)
@@ -131,7 +134,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }))
         .build()?;
 
-    transfer_tx.signature = "mock_contract_signature".to_string();
+    transfer_tx.sign_with_key(CONTRACT_KEY)?;
     let transfer_tx_id = marketplace.submit_tx(&transfer_tx).await?;
     println!("✅ Ownership transferred!");
     println!("   Transaction ID: {}", transfer_tx_id);
@@ -140,7 +143,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 6: Pay seller (minus marketplace fee)
     println!("💸 Step 6: Payment to Seller");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let marketplace_fee = 50;  // 5% fee
     let seller_payment = 950;  // 95% to seller
@@ -159,7 +162,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 7: Query transaction history
     println!("📜 Step 7: Query Transaction History");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let history = marketplace.tx_history(0, 100).await?;
     println!("   Total transactions: {}", history.len());
@@ -176,7 +179,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 8: Check microchain state
     println!("📊 Step 8: Check Microchain State");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let state = marketplace.state().await?;
     println!("   Name: {}", state.name);
@@ -190,7 +193,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 9: Anchor to mainchain for security
     println!("⚓ Step 9: Anchor to Mainchain");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
     println!("   Anchoring ensures all marketplace transactions are");
     println!("   secured by the Ouroboros mainchain's BFT consensus.");
     println!();
@@ -203,7 +206,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 10: Query blocks
     println!("🧱 Step 10: Query Recent Blocks");
-    println!("-".repeat(60));
+    println!("{}", "-".repeat(60));
 
     let blocks = marketplace.blocks(5).await?;
     println!("   Recent {} blocks:", blocks.len());
@@ -216,9 +219,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!();
 
     // Summary
-    println!("=" .repeat(60));
+    println!("{}", "=".repeat(60));
     println!("🎉 Marketplace dApp Demonstration Complete!");
-    println!("=" .repeat(60));
+    println!("{}", "=".repeat(60));
     println!();
     println!("Summary:");
     println!("  ✅ Created marketplace microchain");
@@ -0,0 +1,208 @@
+//! Synchronous client for scripts and CLIs that don't run a tokio runtime
+//!
+//! Mirrors the async [`crate::OuroClient`] API on top of `reqwest::blocking`.
+//! Enabled with the `blocking` Cargo feature.
+
+use crate::client::{
+    tx_status_from_str, AnchorResponse, BalanceResponse, CreateMicrochainResponse,
+    ListMicrochainsResponse, MicrochainBalanceResponse, NonceResponse, TxStatusResponse,
+    TxSubmitResponse,
+};
+use crate::error::{Result, SdkError};
+use crate::transaction::Transaction;
+use crate::types::*;
+use reqwest::blocking::{Client, Response};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// Blocking client for interacting with Ouroboros network
+#[derive(Clone)]
+pub struct OuroClient {
+    base_url: String,
+    client: Client,
+    /// Next nonce to use per sender address, populated on first `send`
+    nonces: HashMap<String, u64>,
+}
+
+impl OuroClient {
+    /// Create a new client
+    pub fn new(node_url: impl Into<String>) -> Self {
+        Self::with_client(node_url, Client::new())
+    }
+
+    /// Create a client with custom reqwest blocking client
+    pub fn with_client(node_url: impl Into<String>, client: Client) -> Self {
+        Self {
+            base_url: node_url.into().trim_end_matches('/').to_string(),
+            client,
+            nonces: HashMap::new(),
+        }
+    }
+
+    /// Get mainchain balance for address
+    pub fn get_balance(&self, address: &str) -> Result<Balance> {
+        let url = format!("{}/balance/{}", self.base_url, address);
+        let response: BalanceResponse = parse_json(self.client.get(&url).send()?)?;
+
+        Ok(Balance {
+            address: address.to_string(),
+            balance: response.balance,
+            pending: response.pending.unwrap_or(0),
+        })
+    }
+
+    /// Get microchain balance
+    pub fn get_microchain_balance(&self, microchain_id: &str, address: &str) -> Result<u64> {
+        let url = format!("{}/microchain/{}/balance/{}", self.base_url, microchain_id, address);
+        let response: MicrochainBalanceResponse = parse_json(self.client.get(&url).send()?)?;
+
+        Ok(response.balance)
+    }
+
+    /// Submit a signed transaction to mainchain as [`Transaction::submit_body`]
+    pub fn submit_transaction(&self, tx: &Transaction) -> Result<String> {
+        let url = format!("{}/tx/submit", self.base_url);
+        let response: TxSubmitResponse =
+            parse_json(self.client.post(&url).json(&tx.submit_body()?).send()?)?;
+
+        if response.success {
+            Ok(response.tx_id)
+        } else {
+            Err(SdkError::TransactionFailed(
+                response.message.unwrap_or_else(|| "Unknown error".to_string())
+            ))
+        }
+    }
+
+    /// Get the next nonce the node expects from an address
+    pub fn get_nonce(&self, address: &str) -> Result<u64> {
+        let url = format!("{}/ouro/nonce/{}", self.base_url, address);
+        let response: NonceResponse = parse_json(self.client.get(&url).send()?)?;
+
+        Ok(response.nonce)
+    }
+
+    /// Assign the sender's next nonce, sign and submit a transaction
+    pub fn send(&mut self, mut tx: Transaction, private_key_hex: &str) -> Result<String> {
+        let nonce = match self.nonces.get(&tx.from) {
+            Some(nonce) => *nonce,
+            None => self.refresh_nonce(&tx.from)?,
+        };
+
+        tx.nonce = nonce;
+        tx.sign_with_key(private_key_hex)?;
+        let tx_id = self.submit_transaction(&tx)?;
+
+        self.nonces.insert(tx.from.clone(), nonce + 1);
+        Ok(tx_id)
+    }
+
+    /// Re-fetch an address's nonce from the node, replacing the cached value
+    pub fn refresh_nonce(&mut self, address: &str) -> Result<u64> {
+        let nonce = self.get_nonce(address)?;
+        self.nonces.insert(address.to_string(), nonce);
+        Ok(nonce)
+    }
+
+    /// Get transaction status
+    pub fn get_transaction_status(&self, tx_id: &str) -> Result<TxStatus> {
+        let url = format!("{}/tx/{}", self.base_url, tx_id);
+        let response: TxStatusResponse = parse_json(self.client.get(&url).send()?)?;
+
+        Ok(tx_status_from_str(&response.status))
+    }
+
+    /// Create a new microchain
+    pub fn create_microchain(&self, config: &MicrochainConfig) -> Result<String> {
+        let url = format!("{}/microchain/create", self.base_url);
+        let response: CreateMicrochainResponse =
+            parse_json(self.client.post(&url).json(config).send()?)?;
+
+        if response.success {
+            Ok(response.microchain_id)
+        } else {
+            Err(SdkError::Other(
+                response.message.unwrap_or_else(|| "Failed to create microchain".to_string())
+            ))
+        }
+    }
+
+    /// Get microchain state
+    pub fn get_microchain_state(&self, microchain_id: &str) -> Result<MicrochainState> {
+        let url = format!("{}/microchain/{}/state", self.base_url, microchain_id);
+        parse_json(self.client.get(&url).send()?)
+    }
+
+    /// List all microchains
+    pub fn list_microchains(&self) -> Result<Vec<MicrochainState>> {
+        let url = format!("{}/microchains", self.base_url);
+        let response: ListMicrochainsResponse = parse_json(self.client.get(&url).send()?)?;
+
+        Ok(response.microchains)
+    }
+
+    /// Trigger manual anchor for a microchain
+    pub fn anchor_microchain(&self, microchain_id: &str) -> Result<String> {
+        let url = format!("{}/microchain/{}/anchor", self.base_url, microchain_id);
+        let response: AnchorResponse = parse_json(self.client.post(&url).send()?)?;
+
+        if response.success {
+            Ok(response.anchor_id)
+        } else {
+            Err(SdkError::AnchorFailed(
+                response.message.unwrap_or_else(|| "Unknown error".to_string())
+            ))
+        }
+    }
+
+    /// Check node health
+    pub fn health_check(&self) -> Result<bool> {
+        let url = format!("{}/health", self.base_url);
+        let response = self.client.get(&url).send()?;
+        Ok(response.status().is_success())
+    }
+}
+
+/// Blocking counterpart of `client::parse_json`
+fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let status = response.status();
+    if !status.is_success() {
+        let message = response.text().unwrap_or_default();
+        return Err(SdkError::Http { status: status.as_u16(), message });
+    }
+
+    Ok(response.json()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocking_get_balance() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/balance/ouro1alice")
+            .with_body(r#"{"balance":1500,"pending":20}"#)
+            .create();
+
+        let client = OuroClient::new(server.url());
+        let balance = client.get_balance("ouro1alice").unwrap();
+
+        mock.assert();
+        assert_eq!(balance.address, "ouro1alice");
+        assert_eq!(balance.balance, 1500);
+        assert_eq!(balance.pending, 20);
+    }
+
+    #[test]
+    fn test_blocking_http_error_is_typed() {
+        let mut server = mockito::Server::new();
+        server.mock("GET", "/balance/ouro1alice")
+            .with_status(503)
+            .create();
+
+        let client = OuroClient::new(server.url());
+        let err = client.get_balance("ouro1alice").unwrap_err();
+        assert!(err.is_retryable());
+    }
+}
@@ -2,14 +2,17 @@ use crate::error::{Result, SdkError};
 use crate::transaction::Transaction;
 use crate::types::*;
 use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
 
 /// Main client for interacting with Ouroboros network
 #[derive(Clone)]
 pub struct OuroClient {
-    base_url: String,
-    client: Client,
+    pub(crate) base_url: String,
+    pub(crate) client: Client,
+    /// Next nonce to use per sender address, populated on first `send`
+    nonces: HashMap<String, u64>,
 }
 
 impl OuroClient {
@@ -18,6 +21,7 @@ impl OuroClient {
         Self {
             base_url: node_url.into().trim_end_matches('/').to_string(),
             client: Client::new(),
+            nonces: HashMap::new(),
         }
     }
 
@@ -26,17 +30,17 @@ impl OuroClient {
         Self {
             base_url: node_url.into().trim_end_matches('/').to_string(),
             client,
+            nonces: HashMap::new(),
         }
     }
 
     /// Get mainchain balance for address
     pub async fn get_balance(&self, address: &str) -> Result<Balance> {
         let url = format!("{}/balance/{}", self.base_url, address);
-        let response: BalanceResponse = self.client.get(&url)
+        let response = self.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: BalanceResponse = parse_json(response).await?;
 
         Ok(Balance {
             address: address.to_string(),
@@ -48,24 +52,24 @@ impl OuroClient {
     /// Get microchain balance
     pub async fn get_microchain_balance(&self, microchain_id: &str, address: &str) -> Result<u64> {
         let url = format!("{}/microchain/{}/balance/{}", self.base_url, microchain_id, address);
-        let response: MicrochainBalanceResponse = self.client.get(&url)
+        let response = self.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: MicrochainBalanceResponse = parse_json(response).await?;
 
         Ok(response.balance)
     }
 
-    /// Submit transaction to mainchain
+    /// Submit a signed transaction to mainchain
+    ///
+    /// Posts [`Transaction::submit_body`], the same body the wallets send.
     pub async fn submit_transaction(&self, tx: &Transaction) -> Result<String> {
         let url = format!("{}/tx/submit", self.base_url);
-        let response: TxSubmitResponse = self.client.post(&url)
-            .json(tx)
+        let response = self.client.post(&url)
+            .json(&tx.submit_body()?)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: TxSubmitResponse = parse_json(response).await?;
 
         if response.success {
             Ok(response.tx_id)
@@ -76,33 +80,62 @@ impl OuroClient {
         }
     }
 
+    /// Get the next nonce the node expects from an address
+    pub async fn get_nonce(&self, address: &str) -> Result<u64> {
+        let url = format!("{}/ouro/nonce/{}", self.base_url, address);
+        let response = self.client.get(&url)
+            .send()
+            .await?;
+        let response: NonceResponse = parse_json(response).await?;
+
+        Ok(response.nonce)
+    }
+
+    /// Assign the sender's next nonce, sign and submit a transaction
+    ///
+    /// The nonce is fetched from the node on the first send for an address and
+    /// incremented locally afterwards, so rapid sends don't collide. Call
+    /// `refresh_nonce` to resync after a failed submission.
+    pub async fn send(&mut self, mut tx: Transaction, private_key_hex: &str) -> Result<String> {
+        let nonce = match self.nonces.get(&tx.from) {
+            Some(nonce) => *nonce,
+            None => self.refresh_nonce(&tx.from).await?,
+        };
+
+        tx.nonce = nonce;
+        tx.sign_with_key(private_key_hex)?;
+        let tx_id = self.submit_transaction(&tx).await?;
+
+        self.nonces.insert(tx.from.clone(), nonce + 1);
+        Ok(tx_id)
+    }
+
+    /// Re-fetch an address's nonce from the node, replacing the cached value
+    pub async fn refresh_nonce(&mut self, address: &str) -> Result<u64> {
+        let nonce = self.get_nonce(address).await?;
+        self.nonces.insert(address.to_string(), nonce);
+        Ok(nonce)
+    }
+
     /// Get transaction status
     pub async fn get_transaction_status(&self, tx_id: &str) -> Result<TxStatus> {
         let url = format!("{}/tx/{}", self.base_url, tx_id);
-        let response: TxStatusResponse = self.client.get(&url)
+        let response = self.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: TxStatusResponse = parse_json(response).await?;
 
-        Ok(match response.status.as_str() {
-            "pending" => TxStatus::Pending,
-            "confirmed" => TxStatus::Confirmed,
-            "failed" => TxStatus::Failed,
-            "anchored" => TxStatus::Anchored,
-            _ => TxStatus::Pending,
-        })
+        Ok(tx_status_from_str(&response.status))
     }
 
     /// Create a new microchain
     pub async fn create_microchain(&self, config: &MicrochainConfig) -> Result<String> {
         let url = format!("{}/microchain/create", self.base_url);
-        let response: CreateMicrochainResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .json(config)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: CreateMicrochainResponse = parse_json(response).await?;
 
         if response.success {
             Ok(response.microchain_id)
@@ -116,11 +149,10 @@ impl OuroClient {
     /// Get microchain state
     pub async fn get_microchain_state(&self, microchain_id: &str) -> Result<MicrochainState> {
         let url = format!("{}/microchain/{}/state", self.base_url, microchain_id);
-        let state: MicrochainState = self.client.get(&url)
+        let response = self.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let state: MicrochainState = parse_json(response).await?;
 
         Ok(state)
     }
@@ -128,11 +160,10 @@ impl OuroClient {
     /// List all microchains
     pub async fn list_microchains(&self) -> Result<Vec<MicrochainState>> {
         let url = format!("{}/microchains", self.base_url);
-        let response: ListMicrochainsResponse = self.client.get(&url)
+        let response = self.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: ListMicrochainsResponse = parse_json(response).await?;
 
         Ok(response.microchains)
     }
@@ -140,11 +171,10 @@ impl OuroClient {
     /// Trigger manual anchor for a microchain
     pub async fn anchor_microchain(&self, microchain_id: &str) -> Result<String> {
         let url = format!("{}/microchain/{}/anchor", self.base_url, microchain_id);
-        let response: AnchorResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: AnchorResponse = parse_json(response).await?;
 
         if response.success {
             Ok(response.anchor_id)
@@ -163,52 +193,81 @@ impl OuroClient {
     }
 }
 
+/// Parse a JSON response body, turning non-2xx statuses into `SdkError::Http`
+/// so callers can tell transient failures from permanent ones
+pub(crate) async fn parse_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+    let status = response.status();
+    if !status.is_success() {
+        let message = response.text().await.unwrap_or_default();
+        return Err(SdkError::Http { status: status.as_u16(), message });
+    }
+
+    Ok(response.json().await?)
+}
+
+/// Map the node's status string onto `TxStatus`, treating unknown values as pending
+pub(crate) fn tx_status_from_str(status: &str) -> TxStatus {
+    match status {
+        "pending" => TxStatus::Pending,
+        "confirmed" => TxStatus::Confirmed,
+        "failed" => TxStatus::Failed,
+        "anchored" => TxStatus::Anchored,
+        _ => TxStatus::Pending,
+    }
+}
+
 // Internal response types
 #[derive(Deserialize)]
-struct BalanceResponse {
-    balance: u64,
-    pending: Option<u64>,
+pub(crate) struct BalanceResponse {
+    pub(crate) balance: u64,
+    pub(crate) pending: Option<u64>,
 }
 
 #[derive(Deserialize)]
-struct MicrochainBalanceResponse {
-    balance: u64,
+pub(crate) struct MicrochainBalanceResponse {
+    pub(crate) balance: u64,
 }
 
 #[derive(Deserialize)]
-struct TxSubmitResponse {
-    success: bool,
-    tx_id: String,
-    message: Option<String>,
+pub(crate) struct NonceResponse {
+    pub(crate) nonce: u64,
 }
 
 #[derive(Deserialize)]
-struct TxStatusResponse {
-    status: String,
+pub(crate) struct TxSubmitResponse {
+    pub(crate) success: bool,
+    pub(crate) tx_id: String,
+    pub(crate) message: Option<String>,
 }
 
 #[derive(Deserialize)]
-struct CreateMicrochainResponse {
-    success: bool,
-    microchain_id: String,
-    message: Option<String>,
+pub(crate) struct TxStatusResponse {
+    pub(crate) status: String,
 }
 
 #[derive(Deserialize)]
-struct ListMicrochainsResponse {
-    microchains: Vec<MicrochainState>,
+pub(crate) struct CreateMicrochainResponse {
+    pub(crate) success: bool,
+    pub(crate) microchain_id: String,
+    pub(crate) message: Option<String>,
 }
 
 #[derive(Deserialize)]
-struct AnchorResponse {
-    success: bool,
-    anchor_id: String,
-    message: Option<String>,
+pub(crate) struct ListMicrochainsResponse {
+    pub(crate) microchains: Vec<MicrochainState>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AnchorResponse {
+    pub(crate) success: bool,
+    pub(crate) anchor_id: String,
+    pub(crate) message: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_client_creation() {
@@ -221,4 +280,139 @@ mod tests {
         let client = OuroClient::new("http://localhost:8001/");
         assert_eq!(client.base_url, "http://localhost:8001");
     }
+
+    const TEST_SECRET_HEX: &str =
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+
+    fn submit_ok() -> String {
+        r#"{"success":true,"tx_id":"tx","message":null}"#.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_send_increments_nonce_locally() {
+        let mut server = mockito::Server::new_async().await;
+        let nonce_mock = server.mock("GET", "/ouro/nonce/ouro1alice")
+            .with_body(r#"{"nonce":5}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let mut submit_mocks = Vec::new();
+        for nonce in 5..8 {
+            submit_mocks.push(server.mock("POST", "/tx/submit")
+                .match_body(mockito::Matcher::PartialJson(json!({ "nonce": nonce })))
+                .with_body(submit_ok())
+                .expect(1)
+                .create_async()
+                .await);
+        }
+
+        let mut client = OuroClient::new(server.url());
+        for _ in 0..3 {
+            let tx = Transaction::new("ouro1alice", "ouro1bob", 100);
+            client.send(tx, TEST_SECRET_HEX).await.unwrap();
+        }
+
+        nonce_mock.assert_async().await;
+        for mock in submit_mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_posts_wallet_submit_body() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/ouro/nonce/ouro1alice")
+            .with_body(r#"{"nonce":1}"#)
+            .create_async()
+            .await;
+        let submit_mock = server.mock("POST", "/tx/submit")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "sender": "ouro1alice",
+                "recipient": "ouro1bob",
+                "payload": { "amount": 100, "fee": 5 },
+                "nonce": 1,
+                "chain_id": crate::transaction::DEFAULT_CHAIN_ID,
+            })))
+            .with_body(submit_ok())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = OuroClient::new(server.url());
+        let tx = Transaction::new("ouro1alice", "ouro1bob", 100).with_fee(5);
+        client.send(tx, TEST_SECRET_HEX).await.unwrap();
+
+        submit_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refresh_nonce_resyncs_from_node() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/ouro/nonce/ouro1alice")
+            .with_body(r#"{"nonce":9}"#)
+            .create_async()
+            .await;
+
+        let mut client = OuroClient::new(server.url());
+        client.nonces.insert("ouro1alice".to_string(), 3);
+
+        assert_eq!(client.refresh_nonce("ouro1alice").await.unwrap(), 9);
+        assert_eq!(client.nonces["ouro1alice"], 9);
+    }
+
+    #[tokio::test]
+    async fn test_failed_send_does_not_advance_nonce() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/ouro/nonce/ouro1alice")
+            .with_body(r#"{"nonce":2}"#)
+            .create_async()
+            .await;
+        server.mock("POST", "/tx/submit")
+            .with_body(r#"{"success":false,"tx_id":"","message":"bad nonce"}"#)
+            .create_async()
+            .await;
+
+        let mut client = OuroClient::new(server.url());
+        let tx = Transaction::new("ouro1alice", "ouro1bob", 100);
+        assert!(client.send(tx, TEST_SECRET_HEX).await.is_err());
+        assert_eq!(client.nonces["ouro1alice"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_http_status_maps_to_retryability() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/balance/busy")
+            .with_status(503)
+            .with_body("node overloaded")
+            .create_async()
+            .await;
+        server.mock("GET", "/balance/bad")
+            .with_status(400)
+            .with_body("invalid address")
+            .create_async()
+            .await;
+
+        let client = OuroClient::new(server.url());
+
+        let err = client.get_balance("busy").await.unwrap_err();
+        assert!(matches!(err, SdkError::Http { status: 503, .. }));
+        assert!(err.is_retryable());
+
+        let err = client.get_balance("bad").await.unwrap_err();
+        assert!(matches!(err, SdkError::Http { status: 400, .. }));
+        assert!(!err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_connection_failure_is_retryable() {
+        // Take a free port, then close it so the connection is refused
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let client = OuroClient::new(format!("http://{}", addr));
+        let err = client.get_balance("ouro1alice").await.unwrap_err();
+        assert!(matches!(err, SdkError::Network(_)));
+        assert!(err.is_retryable());
+    }
 }
@@ -7,6 +7,9 @@ pub enum SdkError {
     #[error("Network error: {0}")]
     Network(#[from] reqwest::Error),
 
+    #[error("HTTP {status}: {message}")]
+    Http { status: u16, message: String },
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
@@ -38,6 +41,29 @@ pub enum SdkError {
     Other(String),
 }
 
+impl SdkError {
+    /// Whether retrying the same request may succeed
+    ///
+    /// Timeouts, connection failures, 408, 429 and 5xx responses are treated
+    /// as transient; everything else (bad requests, invalid signatures,
+    /// insufficient balance, ...) will fail again unchanged.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SdkError::Network(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status().is_some_and(|s| is_retryable_status(s.as_u16()))
+            }
+            SdkError::Http { status, .. } => is_retryable_status(*status),
+            _ => false,
+        }
+    }
+}
+
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
 impl From<String> for SdkError {
     fn from(s: String) -> Self {
         SdkError::Other(s)
@@ -49,3 +75,29 @@ impl From<&str> for SdkError {
         SdkError::Other(s.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http(status: u16) -> SdkError {
+        SdkError::Http { status, message: String::new() }
+    }
+
+    #[test]
+    fn test_transient_statuses_are_retryable() {
+        for status in [408, 429, 500, 502, 503, 504] {
+            assert!(http(status).is_retryable(), "{} should be retryable", status);
+        }
+    }
+
+    #[test]
+    fn test_permanent_failures_are_not_retryable() {
+        for status in [400, 401, 403, 404, 409, 413, 422, 501] {
+            assert!(!http(status).is_retryable(), "{} should not be retryable", status);
+        }
+        assert!(!SdkError::InvalidSignature.is_retryable());
+        assert!(!SdkError::TransactionFailed("nonce too low".into()).is_retryable());
+        assert!(!SdkError::InsufficientBalance { required: 2, available: 1 }.is_retryable());
+    }
+}
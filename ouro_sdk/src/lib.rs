@@ -3,12 +3,16 @@ pub mod transaction;
 pub mod client;
 pub mod types;
 pub mod error;
+pub mod units;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 
-pub use microchain::Microchain;
-pub use transaction::Transaction;
+pub use microchain::{Microchain, MicrochainBuilder};
+pub use transaction::{SigningMessage, Transaction};
 pub use client::OuroClient;
-pub use types::{MicrochainConfig, ConsensusType, AnchorFrequency};
+pub use types::{MicrochainConfig, ConsensusType, AnchorFrequency, MicrochainEvent};
 pub use error::{SdkError, Result};
+pub use units::{DECIMALS, UNITS_PER_OURO};
 
 /// SDK version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -20,6 +24,7 @@ pub mod prelude {
     pub use crate::client::OuroClient;
     pub use crate::types::*;
     pub use crate::error::{SdkError, Result};
+    pub use crate::units::{format_ouro, parse_ouro, DECIMALS, UNITS_PER_OURO};
 }
 
 #[cfg(test)]
@@ -1,7 +1,18 @@
-use crate::client::OuroClient;
+use crate::client::{parse_json, OuroClient};
 use crate::error::{Result, SdkError};
 use crate::transaction::{Transaction, TransactionBuilder};
 use crate::types::*;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+
+/// First delay before reconnecting a dropped event subscription
+const EVENT_RECONNECT_INITIAL: Duration = Duration::from_millis(500);
+
+/// Upper bound for the reconnect backoff
+const EVENT_RECONNECT_MAX: Duration = Duration::from_secs(30);
 
 /// Microchain interface for building dApps
 pub struct Microchain {
@@ -56,12 +67,11 @@ impl Microchain {
     /// Submit a transaction to this microchain
     pub async fn submit_tx(&mut self, tx: &Transaction) -> Result<String> {
         let url = format!("{}/microchain/{}/tx", self.client.base_url, self.id);
-        let response: serde_json::Value = self.client.client.post(&url)
+        let response = self.client.client.post(&url)
             .json(tx)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: serde_json::Value = parse_json(response).await?;
 
         if response["success"].as_bool().unwrap_or(false) {
             self.nonce += 1;
@@ -80,7 +90,7 @@ impl Microchain {
 
     /// Transfer tokens on this microchain
     pub async fn transfer(&mut self, from: &str, to: &str, amount: u64) -> Result<String> {
-        let mut tx = Transaction::new(from, to, amount).with_nonce(self.nonce);
+        let tx = Transaction::new(from, to, amount).with_nonce(self.nonce);
 
         // Sign if keypair is available (TODO: integrate wallet)
         // For now, signature will need to be added externally
@@ -98,30 +108,95 @@ impl Microchain {
         let url = format!("{}/microchain/{}/txs?from={}&to={}",
             self.client.base_url, self.id, from, to);
 
-        let response: TxHistoryResponse = self.client.client.get(&url)
+        let response = self.client.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: TxHistoryResponse = parse_json(response).await?;
 
         Ok(response.transactions)
     }
 
+    /// Subscribe to transaction and block events for this microchain
+    ///
+    /// Connects to `{node}/ws?microchain={id}` and yields events in the order
+    /// they arrive. The frame format is defined by this SDK (see
+    /// [`MicrochainEvent`]); nodes that want to feed it must send one JSON text
+    /// frame per event.
+    ///
+    /// Every reconnect, whether after a failed connect or a dropped socket,
+    /// waits for the current backoff and doubles it (500ms up to 30s). The
+    /// backoff resets once an event frame is received, so a node that accepts
+    /// and immediately closes connections isn't hammered. The stream only ends
+    /// when it is dropped; frames that fail to decode are yielded as errors.
+    pub fn subscribe_events(&self) -> BoxStream<'static, Result<MicrochainEvent>> {
+        let url = format!(
+            "{}/ws?microchain={}",
+            self.client.base_url.replacen("http", "ws", 1),
+            self.id
+        );
+
+        event_stream(url, tokio::time::sleep)
+    }
+
     /// Get latest blocks from this microchain
     pub async fn blocks(&self, limit: u32) -> Result<Vec<BlockHeader>> {
         let url = format!("{}/microchain/{}/blocks?limit={}",
             self.client.base_url, self.id, limit);
 
-        let response: BlocksResponse = self.client.client.get(&url)
+        let response = self.client.client.get(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: BlocksResponse = parse_json(response).await?;
 
         Ok(response.blocks)
     }
 }
 
+/// `subscribe_events` stream over `url`, waiting out each reconnect backoff with `sleep`
+fn event_stream<S, F>(url: String, sleep: S) -> BoxStream<'static, Result<MicrochainEvent>>
+where
+    S: Fn(Duration) -> F + Send + Sync + 'static,
+    F: Future<Output = ()> + Send,
+{
+    let sleep = Arc::new(sleep);
+
+    // (url, socket, backoff, whether the next connect is a reconnect)
+    let initial = (url, None, EVENT_RECONNECT_INITIAL, false);
+    stream::unfold(initial, move |(url, mut socket, mut backoff, mut reconnect)| {
+        let sleep = Arc::clone(&sleep);
+        async move {
+            loop {
+                let ws = match socket.as_mut() {
+                    Some(ws) => ws,
+                    None => {
+                        if reconnect {
+                            sleep(backoff).await;
+                            backoff = (backoff * 2).min(EVENT_RECONNECT_MAX);
+                        }
+                        reconnect = true;
+
+                        match tokio_tungstenite::connect_async(url.as_str()).await {
+                            Ok((ws, _)) => socket.insert(ws),
+                            Err(_) => continue,
+                        }
+                    }
+                };
+
+                match ws.next().await {
+                    Some(Ok(Message::Text(text))) => {
+                        backoff = EVENT_RECONNECT_INITIAL;
+                        let event = serde_json::from_str(&text).map_err(SdkError::from);
+                        return Some((event, (url, socket, backoff, reconnect)));
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => socket = None,
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
 // Internal response types
 #[derive(serde::Deserialize)]
 struct TxHistoryResponse {
@@ -189,4 +264,177 @@ mod tests {
         assert_eq!(builder.config.name, "TestChain");
         assert_eq!(builder.config.block_time_secs, 10);
     }
+
+    fn block_event(height: u64) -> String {
+        serde_json::json!({
+            "type": "block",
+            "height": height,
+            "hash": format!("hash{}", height),
+            "previous_hash": format!("hash{}", height - 1),
+            "timestamp": "2024-01-01T00:00:00Z",
+            "tx_count": 1
+        })
+        .to_string()
+    }
+
+    /// Mock node that serves each connection one batch of frames, then closes it
+    async fn mock_ws_node(batches: Vec<Vec<String>>) -> String {
+        use futures_util::SinkExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            for batch in batches {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+                for frame in batch {
+                    ws.send(Message::Text(frame)).await.unwrap();
+                }
+                ws.close(None).await.unwrap();
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    fn microchain_at(node_url: String) -> Microchain {
+        Microchain {
+            id: "mc1".to_string(),
+            client: OuroClient::new(node_url),
+            nonce: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_in_order() {
+        let tx = Transaction::new("ouro1alice", "ouro1bob", 5);
+        let mut tx_frame = serde_json::to_value(&tx).unwrap();
+        tx_frame["type"] = "transaction".into();
+
+        let node = mock_ws_node(vec![vec![
+            tx_frame.to_string(),
+            block_event(1),
+            block_event(2),
+        ]])
+        .await;
+        let mut events = microchain_at(node).subscribe_events();
+
+        match events.next().await.unwrap().unwrap() {
+            MicrochainEvent::Transaction(received) => assert_eq!(received.id, tx.id),
+            other => panic!("expected transaction, got {:?}", other),
+        }
+        for height in [1, 2] {
+            match events.next().await.unwrap().unwrap() {
+                MicrochainEvent::Block(block) => assert_eq!(block.height, height),
+                other => panic!("expected block, got {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_reconnects_after_close() {
+        let node = mock_ws_node(vec![vec![block_event(1)], vec![block_event(2)]]).await;
+        let mut events = microchain_at(node).subscribe_events();
+
+        for height in [1, 2] {
+            let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .expect("event before timeout");
+            match event.unwrap().unwrap() {
+                MicrochainEvent::Block(block) => assert_eq!(block.height, height),
+                other => panic!("expected block, got {:?}", other),
+            }
+        }
+    }
+
+    /// Sleep for `event_stream` that records each requested delay and returns at once
+    fn recording_sleep() -> (
+        impl Fn(Duration) -> std::future::Ready<()> + Send + Sync + 'static,
+        tokio::sync::mpsc::UnboundedReceiver<Duration>,
+    ) {
+        let (delays_tx, delays) = tokio::sync::mpsc::unbounded_channel();
+        let sleep = move |delay| {
+            let _ = delays_tx.send(delay);
+            std::future::ready(())
+        };
+        (sleep, delays)
+    }
+
+    fn ws_url(node: &str) -> String {
+        format!("{}/ws?microchain=mc1", node.replacen("http", "ws", 1))
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_backs_off_when_node_closes_immediately() {
+        use futures_util::SinkExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let node = format!("http://{}", listener.local_addr().unwrap());
+
+        // Accepts every connection and closes it without sending an event
+        tokio::spawn(async move {
+            loop {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+                let _ = ws.close(None).await;
+                let _ = ws.flush().await;
+            }
+        });
+
+        let (sleep, mut delays) = recording_sleep();
+        let mut events = event_stream(ws_url(&node), sleep);
+        let subscriber = tokio::spawn(async move { events.next().await });
+
+        let mut recorded = Vec::new();
+        for _ in 0..7 {
+            let delay = tokio::time::timeout(Duration::from_secs(5), delays.recv())
+                .await
+                .expect("reconnect before timeout");
+            recorded.push(delay.unwrap());
+        }
+        subscriber.abort();
+
+        let expected: Vec<Duration> = [500, 1_000, 2_000, 4_000, 8_000, 16_000, 30_000]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        assert_eq!(recorded, expected);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_backoff_resets_after_event() {
+        // Event, empty connection, event: the empty one grows the backoff, events reset it
+        let node = mock_ws_node(vec![vec![block_event(1)], vec![], vec![block_event(2)]]).await;
+
+        let (sleep, mut delays) = recording_sleep();
+        let mut events = event_stream(ws_url(&node), sleep);
+        for height in [1, 2] {
+            let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .expect("event before timeout");
+            match event.unwrap().unwrap() {
+                MicrochainEvent::Block(block) => assert_eq!(block.height, height),
+                other => panic!("expected block, got {:?}", other),
+            }
+        }
+
+        let mut recorded = Vec::new();
+        while let Ok(delay) = delays.try_recv() {
+            recorded.push(delay);
+        }
+        assert_eq!(recorded, [Duration::from_millis(500), Duration::from_secs(1)]);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_surfaces_bad_frames() {
+        let node = mock_ws_node(vec![vec!["not json".to_string(), block_event(3)]]).await;
+        let mut events = microchain_at(node).subscribe_events();
+
+        assert!(events.next().await.unwrap().is_err());
+        assert!(matches!(
+            events.next().await.unwrap().unwrap(),
+            MicrochainEvent::Block(BlockHeader { height: 3, .. })
+        ));
+    }
 }
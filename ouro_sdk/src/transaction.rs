@@ -1,8 +1,98 @@
 use crate::error::{Result, SdkError};
-use ed25519_dalek::{Keypair, Signature, Signer};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Chain ID the node expects in every signing message
+pub const DEFAULT_CHAIN_ID: &str = "ouroboros-mainnet-1";
+
+fn default_chain_id() -> String {
+    DEFAULT_CHAIN_ID.to_string()
+}
+
+/// Fields covered by a transaction signature
+///
+/// This is the single builder for the signing message; midgard-wallet and the
+/// wallet UI construct their messages through it rather than keeping copies.
+#[derive(Debug, Clone, Copy)]
+pub struct SigningMessage<'a> {
+    pub chain_id: &'a str,
+    pub nonce: u64,
+    pub id: &'a str,
+    pub sender: &'a str,
+    pub recipient: &'a str,
+    pub amount: u64,
+    pub fee: u64,
+    /// Unix seconds
+    pub timestamp: i64,
+    pub parents: &'a [String],
+    pub payload: Option<&'a str>,
+}
+
+impl SigningMessage<'_> {
+    /// Serialize in signing order: chain_id, nonce, id, sender, recipient,
+    /// amount, fee, timestamp, parents, payload. Integers are little-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut msg = Vec::new();
+        msg.extend_from_slice(self.chain_id.as_bytes());
+        msg.extend_from_slice(&self.nonce.to_le_bytes());
+        msg.extend_from_slice(self.id.as_bytes());
+        msg.extend_from_slice(self.sender.as_bytes());
+        msg.extend_from_slice(self.recipient.as_bytes());
+        msg.extend_from_slice(&self.amount.to_le_bytes());
+        msg.extend_from_slice(&self.fee.to_le_bytes());
+        msg.extend_from_slice(&self.timestamp.to_le_bytes());
+        for parent in self.parents {
+            msg.extend_from_slice(parent.as_bytes());
+        }
+        if let Some(payload) = self.payload {
+            msg.extend_from_slice(payload.as_bytes());
+        }
+        msg
+    }
+
+    /// Check a hex Ed25519 signature over these fields against a hex public key
+    pub fn verify(&self, public_key_hex: &str, signature_hex: &str) -> Result<bool> {
+        let public_bytes: [u8; 32] = hex::decode(public_key_hex)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(SdkError::InvalidSignature)?;
+        let signature_bytes: [u8; 64] = hex::decode(signature_hex)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(SdkError::InvalidSignature)?;
+
+        let verifying_key = VerifyingKey::from_bytes(&public_bytes)
+            .map_err(|_| SdkError::InvalidSignature)?;
+        let signature = Signature::from_bytes(&signature_bytes);
+
+        Ok(verifying_key.verify(&self.to_bytes(), &signature).is_ok())
+    }
+
+    /// Body for the node's `/tx/submit` endpoint
+    ///
+    /// Carries every signed field, so the node can rebuild the signing message
+    /// however long after signing the transaction is broadcast.
+    pub fn submit_body(&self, public_key_hex: &str, signature_hex: &str) -> serde_json::Value {
+        serde_json::json!({
+            "tx_hash": self.id,
+            "sender": self.sender,
+            "recipient": self.recipient,
+            "signature": signature_hex,
+            "payload": {
+                "amount": self.amount,
+                "fee": self.fee,
+                "public_key": public_key_hex
+            },
+            "nonce": self.nonce,
+            "chain_id": self.chain_id,
+            "timestamp": self.timestamp,
+            "parents": self.parents,
+            "data": self.payload
+        })
+    }
+}
+
 /// Transaction for microchain or mainchain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transaction {
@@ -21,6 +111,22 @@ pub struct Transaction {
     /// Transaction nonce (prevents replay)
     pub nonce: u64,
 
+    /// Transaction fee in smallest units
+    #[serde(default)]
+    pub fee: u64,
+
+    /// Chain ID (prevents cross-chain replay)
+    #[serde(default = "default_chain_id")]
+    pub chain_id: String,
+
+    /// Parent transaction IDs (DAG edges)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<String>,
+
+    /// Signer's Ed25519 public key (hex), set by `sign`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub public_key: String,
+
     /// Ed25519 signature
     pub signature: String,
 
@@ -42,6 +148,10 @@ impl Transaction {
             to: to.into(),
             amount,
             nonce: 0,
+            fee: 0,
+            chain_id: default_chain_id(),
+            parents: Vec::new(),
+            public_key: String::new(),
             signature: String::new(),
             data: None,
             timestamp: Some(chrono::Utc::now().to_rfc3339()),
@@ -54,6 +164,24 @@ impl Transaction {
         self
     }
 
+    /// Set fee
+    pub fn with_fee(mut self, fee: u64) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Set chain ID
+    pub fn with_chain_id(mut self, chain_id: impl Into<String>) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+
+    /// Set parent transaction IDs
+    pub fn with_parents(mut self, parents: Vec<String>) -> Self {
+        self.parents = parents;
+        self
+    }
+
     /// Add custom data
     pub fn with_data(mut self, data: serde_json::Value) -> Self {
         self.data = Some(data);
@@ -61,37 +189,76 @@ impl Transaction {
     }
 
     /// Sign transaction with keypair
-    pub fn sign(&mut self, keypair: &Keypair) -> Result<()> {
-        let message = self.signing_message();
-        let signature = keypair.sign(message.as_bytes());
+    pub fn sign(&mut self, signing_key: &SigningKey) -> Result<()> {
+        self.public_key = hex::encode(signing_key.verifying_key().to_bytes());
+        let message = self.signing_message()?;
+        let signature = signing_key.sign(&message);
         self.signature = hex::encode(signature.to_bytes());
         Ok(())
     }
 
     /// Sign transaction with private key hex
+    ///
+    /// Accepts either a 32-byte secret key or a 64-byte keypair (secret followed
+    /// by public key, as exported by the wallets).
     pub fn sign_with_key(&mut self, private_key_hex: &str) -> Result<()> {
         let private_bytes = hex::decode(private_key_hex)
             .map_err(|_| SdkError::InvalidSignature)?;
-        let secret = ed25519_dalek::SecretKey::from_bytes(&private_bytes)
-            .map_err(|_| SdkError::InvalidSignature)?;
-        let public = ed25519_dalek::PublicKey::from(&secret);
-        let keypair = Keypair { secret, public };
-        self.sign(&keypair)
+        let signing_key = match private_bytes.len() {
+            32 => SigningKey::from_bytes(&private_bytes.try_into().unwrap()),
+            64 => SigningKey::from_keypair_bytes(&private_bytes.try_into().unwrap())
+                .map_err(|_| SdkError::InvalidSignature)?,
+            _ => return Err(SdkError::InvalidSignature),
+        };
+        self.sign(&signing_key)
     }
 
-    /// Get signing message
-    fn signing_message(&self) -> String {
-        format!(
-            "{}:{}:{}:{}:{}",
-            self.id, self.from, self.to, self.amount, self.nonce
-        )
+    /// Payload text covered by the signature
+    ///
+    /// A JSON string is signed as its raw contents, anything else as compact
+    /// JSON. This is the text midgard-wallet carries in its `payload` field.
+    pub fn payload(&self) -> Option<String> {
+        self.data.as_ref().map(|data| match data {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
     }
 
-    /// Verify transaction signature
+    /// Run `f` over this transaction's signed fields
+    fn with_signing_message<T>(&self, f: impl FnOnce(SigningMessage<'_>) -> T) -> Result<T> {
+        let timestamp = self.timestamp.as_deref()
+            .ok_or_else(|| SdkError::InvalidConfig("Missing timestamp".into()))?;
+        let timestamp = chrono::DateTime::parse_from_rfc3339(timestamp)
+            .map_err(|e| SdkError::InvalidConfig(format!("Invalid timestamp: {}", e)))?;
+        let payload = self.payload();
+
+        Ok(f(SigningMessage {
+            chain_id: &self.chain_id,
+            nonce: self.nonce,
+            id: &self.id,
+            sender: &self.from,
+            recipient: &self.to,
+            amount: self.amount,
+            fee: self.fee,
+            timestamp: timestamp.timestamp(),
+            parents: &self.parents,
+            payload: payload.as_deref(),
+        }))
+    }
+
+    /// Get signing message (see [`SigningMessage`])
+    pub fn signing_message(&self) -> Result<Vec<u8>> {
+        self.with_signing_message(|message| message.to_bytes())
+    }
+
+    /// Verify transaction signature against the embedded public key
     pub fn verify(&self) -> Result<bool> {
-        // TODO: Implement signature verification
-        // Need public key from 'from' address
-        Ok(!self.signature.is_empty())
+        self.with_signing_message(|message| message.verify(&self.public_key, &self.signature))?
+    }
+
+    /// Body for the node's `/tx/submit` endpoint (see [`SigningMessage::submit_body`])
+    pub fn submit_body(&self) -> Result<serde_json::Value> {
+        self.with_signing_message(|message| message.submit_body(&self.public_key, &self.signature))
     }
 }
 
@@ -101,6 +268,7 @@ pub struct TransactionBuilder {
     to: Option<String>,
     amount: Option<u64>,
     nonce: u64,
+    fee: u64,
     data: Option<serde_json::Value>,
 }
 
@@ -111,6 +279,7 @@ impl TransactionBuilder {
             to: None,
             amount: None,
             nonce: 0,
+            fee: 0,
             data: None,
         }
     }
@@ -135,6 +304,11 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn fee(mut self, fee: u64) -> Self {
+        self.fee = fee;
+        self
+    }
+
     pub fn data(mut self, data: serde_json::Value) -> Self {
         self.data = Some(data);
         self
@@ -145,7 +319,9 @@ impl TransactionBuilder {
         let to = self.to.ok_or(SdkError::InvalidConfig("Missing 'to' address".into()))?;
         let amount = self.amount.ok_or(SdkError::InvalidConfig("Missing amount".into()))?;
 
-        let mut tx = Transaction::new(from, to, amount).with_nonce(self.nonce);
+        let mut tx = Transaction::new(from, to, amount)
+            .with_nonce(self.nonce)
+            .with_fee(self.fee);
         if let Some(data) = self.data {
             tx = tx.with_data(data);
         }
@@ -198,4 +374,103 @@ mod tests {
 
         assert!(result.is_err()); // Missing 'to' address
     }
+
+    const TEST_SECRET_HEX: &str =
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+
+    /// Signing bytes and signature produced for this transaction by
+    /// midgard-wallet's builder before it moved onto `SigningMessage`.
+    ///
+    /// There is no node verifier in this tree to test against, so this pins
+    /// every client to the layout the wallet already shipped with.
+    const GOLDEN_MESSAGE_HEX: &str = "6f75726f626f726f732d6d61696e6e65742d31070000000000000030303030303030302d303030302d343030302d383030302d3030303030303030303030316f75726f31616c6963656f75726f31626f62e8030000000000000a0000000000000000f1536500000000703170327b226d6574686f64223a227472616e73666572227d";
+    const GOLDEN_SIGNATURE_HEX: &str = "7d20e46eefdf098c88b0515facf6c2d21b24d74c59f3764b2ed12e233b9b35ffe21d44a0237803ede0ef2b89f5e9c80d75a53a0d5dfab5607e05a43c8cb32400";
+
+    #[test]
+    fn test_signing_message_matches_golden_vector() {
+        let mut tx = Transaction::new("ouro1alice", "ouro1bob", 1000)
+            .with_nonce(7)
+            .with_fee(10)
+            .with_parents(vec!["p1".to_string(), "p2".to_string()])
+            .with_data(serde_json::json!({"method": "transfer"}));
+        tx.id = "00000000-0000-4000-8000-000000000001".to_string();
+        tx.timestamp = Some("2023-11-14T22:13:20Z".to_string());
+        tx.sign_with_key(TEST_SECRET_HEX).unwrap();
+
+        assert_eq!(hex::encode(tx.signing_message().unwrap()), GOLDEN_MESSAGE_HEX);
+        assert_eq!(tx.signature, GOLDEN_SIGNATURE_HEX);
+
+        let public: [u8; 32] = hex::decode(&tx.public_key).unwrap().try_into().unwrap();
+        let signature: [u8; 64] = hex::decode(&tx.signature).unwrap().try_into().unwrap();
+        VerifyingKey::from_bytes(&public)
+            .unwrap()
+            .verify_strict(&hex::decode(GOLDEN_MESSAGE_HEX).unwrap(), &Signature::from_bytes(&signature))
+            .unwrap();
+    }
+
+    /// The node must be able to rebuild the signed message from the body alone
+    #[test]
+    fn test_submit_body_carries_every_signed_field() {
+        let mut tx = Transaction::new("ouro1alice", "ouro1bob", 1000)
+            .with_nonce(7)
+            .with_fee(10)
+            .with_parents(vec!["p1".to_string()])
+            .with_data(serde_json::json!("memo"));
+        tx.sign_with_key(TEST_SECRET_HEX).unwrap();
+
+        let body = tx.submit_body().unwrap();
+        let parents: Vec<String> = serde_json::from_value(body["parents"].clone()).unwrap();
+        let message = SigningMessage {
+            chain_id: body["chain_id"].as_str().unwrap(),
+            nonce: body["nonce"].as_u64().unwrap(),
+            id: body["tx_hash"].as_str().unwrap(),
+            sender: body["sender"].as_str().unwrap(),
+            recipient: body["recipient"].as_str().unwrap(),
+            amount: body["payload"]["amount"].as_u64().unwrap(),
+            fee: body["payload"]["fee"].as_u64().unwrap(),
+            timestamp: body["timestamp"].as_i64().unwrap(),
+            parents: &parents,
+            payload: body["data"].as_str(),
+        };
+
+        assert_eq!(message.to_bytes(), tx.signing_message().unwrap());
+        assert!(message
+            .verify(body["payload"]["public_key"].as_str().unwrap(), body["signature"].as_str().unwrap())
+            .unwrap());
+    }
+
+    #[test]
+    fn test_string_payload_is_signed_raw() {
+        let tx = Transaction::new("ouro1alice", "ouro1bob", 1).with_data(serde_json::json!("memo"));
+        assert_eq!(tx.payload().as_deref(), Some("memo"));
+
+        let tx = tx.with_data(serde_json::json!({"a": 1}));
+        assert_eq!(tx.payload().as_deref(), Some(r#"{"a":1}"#));
+    }
+
+    #[test]
+    fn test_sign_with_keypair_hex() {
+        let secret: [u8; 32] = hex::decode(TEST_SECRET_HEX).unwrap().try_into().unwrap();
+        let keypair_hex = hex::encode(SigningKey::from_bytes(&secret).to_keypair_bytes());
+
+        let mut tx = Transaction::new("ouro1alice", "ouro1bob", 1000);
+        tx.sign_with_key(&keypair_hex).unwrap();
+        assert!(tx.verify().unwrap());
+    }
+
+    #[test]
+    fn test_tampered_transaction_fails_verification() {
+        let mut tx = Transaction::new("ouro1alice", "ouro1bob", 1000).with_nonce(1);
+        tx.sign_with_key(TEST_SECRET_HEX).unwrap();
+
+        tx.amount = 1_000_000;
+        assert!(!tx.verify().unwrap());
+    }
+
+    #[test]
+    fn test_sign_with_invalid_key() {
+        let mut tx = Transaction::new("ouro1alice", "ouro1bob", 1000);
+        assert!(tx.sign_with_key("not_hex").is_err());
+        assert!(tx.sign_with_key("abcd").is_err());
+    }
 }
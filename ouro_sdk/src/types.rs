@@ -1,20 +1,16 @@
+use crate::transaction::Transaction;
 use serde::{Deserialize, Serialize};
 
 /// Consensus type for microchain
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum ConsensusType {
     /// Single validator (fast, centralized)
+    #[default]
     SingleValidator,
     /// BFT consensus (slower, decentralized)
     Bft { validator_count: u32 },
 }
 
-impl Default for ConsensusType {
-    fn default() -> Self {
-        ConsensusType::SingleValidator
-    }
-}
-
 /// How often to anchor to subchain/mainchain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AnchorFrequency {
@@ -133,6 +129,20 @@ pub struct BlockHeader {
     pub tx_count: u32,
 }
 
+/// Event frame on the `/ws?microchain={id}` feed
+///
+/// SDK-defined contract: each WebSocket text frame is one JSON object whose
+/// `type` is `"transaction"` or `"block"`, with the remaining fields of
+/// [`Transaction`] or [`BlockHeader`] alongside it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MicrochainEvent {
+    /// Transaction admitted to the microchain
+    Transaction(Transaction),
+    /// Block produced on the microchain
+    Block(BlockHeader),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,91 @@
+use crate::error::{Result, SdkError};
+
+/// Number of decimal places in one OURO
+pub const DECIMALS: u32 = 12;
+
+/// Smallest units per OURO (1 OURO = 10^DECIMALS units)
+pub const UNITS_PER_OURO: u64 = 10u64.pow(DECIMALS);
+
+/// Convert a human-readable OURO amount (e.g. "1.5") into smallest units
+///
+/// Parsing is exact: amounts with more than `DECIMALS` fractional digits
+/// or that overflow `u64` are rejected instead of being rounded.
+pub fn parse_ouro(amount: &str) -> Result<u64> {
+    let invalid = || SdkError::InvalidConfig(format!("Invalid OURO amount: {}", amount));
+
+    let amount = amount.trim();
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    if !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+        || fraction.len() > DECIMALS as usize
+    {
+        return Err(invalid());
+    }
+
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        let scale = 10u64.pow(DECIMALS - fraction.len() as u32);
+        fraction.parse::<u64>().map_err(|_| invalid())? * scale
+    };
+
+    whole
+        .checked_mul(UNITS_PER_OURO)
+        .and_then(|units| units.checked_add(fraction))
+        .ok_or_else(invalid)
+}
+
+/// Format smallest units as a human-readable OURO amount
+///
+/// Trailing zeros in the fractional part are trimmed, so 1_500_000_000_000
+/// units formats as "1.5".
+pub fn format_ouro(units: u64) -> String {
+    let whole = units / UNITS_PER_OURO;
+    let fraction = units % UNITS_PER_OURO;
+
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{:0width$}", fraction, width = DECIMALS as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference conversions shared with midgard_wallet and the wallet UI
+    const CASES_JSON: &str = include_str!("../testdata/units_cases.json");
+
+    fn cases() -> Vec<(String, u64)> {
+        serde_json::from_str(CASES_JSON).unwrap()
+    }
+
+    #[test]
+    fn test_units_per_ouro() {
+        assert_eq!(UNITS_PER_OURO, 1_000_000_000_000);
+    }
+
+    #[test]
+    fn test_parse_and_format_round_trip() {
+        for (human, units) in cases() {
+            assert_eq!(parse_ouro(&human).unwrap(), units);
+            assert_eq!(format_ouro(units), human);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_amounts() {
+        assert!(parse_ouro("").is_err());
+        assert!(parse_ouro(".").is_err());
+        assert!(parse_ouro("-1").is_err());
+        assert!(parse_ouro("1.0000000000001").is_err()); // 13 decimals
+        assert!(parse_ouro("18446744073709551615").is_err()); // overflows u64 units
+    }
+}
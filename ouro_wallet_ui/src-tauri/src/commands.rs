@@ -1,7 +1,37 @@
+use crate::transaction::{microchain_transfer, Transaction};
+use crate::units::{format_ouro, parse_amount};
 use crate::wallet::{Wallet, WalletInfo};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tauri::AppHandle;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+
+/// How long a wallet stays unlocked after `unlock_wallet`
+const SESSION_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Decrypted wallet cached by `unlock_wallet` until the session times out
+#[derive(Default)]
+pub struct WalletSession(Mutex<Option<(Wallet, Instant)>>);
+
+impl WalletSession {
+    fn set(&self, wallet: Wallet) {
+        if let Ok(mut session) = self.0.lock() {
+            *session = Some((wallet, Instant::now()));
+        }
+    }
+
+    fn get(&self) -> Option<Wallet> {
+        let mut session = self.0.lock().ok()?;
+        match session.as_ref() {
+            Some((wallet, unlocked_at)) if unlocked_at.elapsed() < SESSION_TIMEOUT => Some(wallet.clone()),
+            _ => {
+                *session = None;
+                None
+            }
+        }
+    }
+}
 
 // Response types
 #[derive(Debug, Serialize)]
@@ -14,6 +44,10 @@ pub struct CreateWalletResponse {
 pub struct BalanceResponse {
     pub balance: u64,
     pub pending: u64,
+    /// `balance` formatted in OURO for display
+    pub balance_ouro: String,
+    /// `pending` formatted in OURO for display
+    pub pending_ouro: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -30,10 +64,15 @@ pub struct TransactionRecord {
     pub from: String,
     pub to: String,
     pub amount: u64,
+    /// `amount` formatted in OURO for display
+    pub amount_ouro: String,
     pub timestamp: String,
     pub status: String,
 }
 
+/// Fee used when the UI doesn't specify one (same default as midgard-wallet)
+const DEFAULT_FEE: u64 = 1000;
+
 // Helper to get wallet path
 fn get_wallet_path(app: &AppHandle) -> PathBuf {
     app.path_resolver()
@@ -42,16 +81,68 @@ fn get_wallet_path(app: &AppHandle) -> PathBuf {
         .join("wallet.json")
 }
 
+fn check_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.is_empty() {
+        return Err("Passphrase must not be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Load and decrypt the wallet off the async runtime
+///
+/// scrypt takes ~128 MiB and a noticeable amount of CPU, so it runs on
+/// tokio's blocking pool instead of a runtime worker.
+async fn load_unlocked(app: &AppHandle, passphrase: String) -> Result<Wallet, String> {
+    let wallet_path = get_wallet_path(app);
+
+    tokio::task::spawn_blocking(move || {
+        let mut wallet = Wallet::load(&wallet_path)?;
+        wallet.unlock(&passphrase)?;
+        Ok(wallet)
+    })
+    .await
+    .map_err(|e| format!("Unlock task failed: {}", e))?
+}
+
+/// Encrypt and save the wallet on the blocking pool, like `load_unlocked`
+async fn save_sealed(app: &AppHandle, mut wallet: Wallet, passphrase: String) -> Result<Wallet, String> {
+    let wallet_path = get_wallet_path(app);
+
+    tokio::task::spawn_blocking(move || {
+        wallet.save(&wallet_path, &passphrase)?;
+        Ok(wallet)
+    })
+    .await
+    .map_err(|e| format!("Save task failed: {}", e))?
+}
+
+/// Wallet with its key decrypted, from the passphrase or the unlocked session
+async fn signing_wallet(
+    app: &AppHandle,
+    session: &WalletSession,
+    passphrase: Option<String>,
+) -> Result<Wallet, String> {
+    if let Some(passphrase) = passphrase {
+        return load_unlocked(app, passphrase).await;
+    }
+
+    session
+        .get()
+        .ok_or("Wallet is locked. Unlock it with your passphrase.".to_string())
+}
+
 /// Create a new wallet
 #[tauri::command]
-pub fn create_wallet(
+pub async fn create_wallet(
     name: Option<String>,
+    passphrase: String,
     app: AppHandle,
+    session: State<'_, WalletSession>,
 ) -> Result<CreateWalletResponse, String> {
+    check_passphrase(&passphrase)?;
     let (wallet, mnemonic) = Wallet::generate(name);
-    let wallet_path = get_wallet_path(&app);
-
-    wallet.save(&wallet_path)?;
+    let wallet = save_sealed(&app, wallet, passphrase).await?;
+    session.set(wallet.clone());
 
     Ok(CreateWalletResponse {
         wallet: wallet.into(),
@@ -61,30 +152,34 @@ pub fn create_wallet(
 
 /// Import wallet from mnemonic
 #[tauri::command]
-pub fn import_wallet(
+pub async fn import_wallet(
     mnemonic: String,
     name: Option<String>,
+    passphrase: String,
     app: AppHandle,
+    session: State<'_, WalletSession>,
 ) -> Result<WalletInfo, String> {
+    check_passphrase(&passphrase)?;
     let wallet = Wallet::from_mnemonic(&mnemonic, name)?;
-    let wallet_path = get_wallet_path(&app);
-
-    wallet.save(&wallet_path)?;
+    let wallet = save_sealed(&app, wallet, passphrase).await?;
+    session.set(wallet.clone());
 
     Ok(wallet.into())
 }
 
 /// Import wallet from private key
 #[tauri::command]
-pub fn import_from_key(
+pub async fn import_from_key(
     private_key: String,
     name: Option<String>,
+    passphrase: String,
     app: AppHandle,
+    session: State<'_, WalletSession>,
 ) -> Result<WalletInfo, String> {
+    check_passphrase(&passphrase)?;
     let wallet = Wallet::from_private_key(&private_key, name)?;
-    let wallet_path = get_wallet_path(&app);
-
-    wallet.save(&wallet_path)?;
+    let wallet = save_sealed(&app, wallet, passphrase).await?;
+    session.set(wallet.clone());
 
     Ok(wallet.into())
 }
@@ -102,13 +197,24 @@ pub fn get_wallet_info(app: AppHandle) -> Result<WalletInfo, String> {
     Ok(wallet.into())
 }
 
-/// Export mnemonic (requires wallet to have private key)
+/// Decrypt the wallet and keep it unlocked for `SESSION_TIMEOUT`
 #[tauri::command]
-pub fn export_mnemonic(app: AppHandle) -> Result<String, String> {
-    let wallet_path = get_wallet_path(&app);
-    let wallet = Wallet::load(&wallet_path)?;
+pub async fn unlock_wallet(
+    passphrase: String,
+    app: AppHandle,
+    session: State<'_, WalletSession>,
+) -> Result<WalletInfo, String> {
+    let wallet = load_unlocked(&app, passphrase).await?;
+    session.set(wallet.clone());
+
+    Ok(wallet.into())
+}
+
+/// Export the private key (always requires the passphrase, even when unlocked)
+#[tauri::command]
+pub async fn export_mnemonic(passphrase: String, app: AppHandle) -> Result<String, String> {
+    let wallet = load_unlocked(&app, passphrase).await?;
 
-    // For security, in production you'd want to verify password here
     wallet
         .private_key
         .ok_or("Wallet does not have private key stored".to_string())
@@ -134,19 +240,24 @@ pub async fn get_balance(node_url: String, app: AppHandle) -> Result<BalanceResp
         .await
         .map_err(|e| format!("JSON error: {}", e))?;
 
+    let balance = data["balance"].as_u64().unwrap_or(0);
+    let pending = data["pending"].as_u64().unwrap_or(0);
+
     Ok(BalanceResponse {
-        balance: data["balance"].as_u64().unwrap_or(0),
-        pending: data["pending"].as_u64().unwrap_or(0),
+        balance,
+        pending,
+        balance_ouro: format_ouro(balance),
+        pending_ouro: format_ouro(pending),
     })
 }
 
-/// Get microchain balance
+/// Get microchain balance, formatted in OURO
 #[tauri::command]
 pub async fn get_microchain_balance(
     node_url: String,
     microchain_id: String,
     app: AppHandle,
-) -> Result<u64, String> {
+) -> Result<String, String> {
     let wallet_path = get_wallet_path(&app);
     let wallet = Wallet::load(&wallet_path)?;
 
@@ -169,7 +280,31 @@ pub async fn get_microchain_balance(
         .await
         .map_err(|e| format!("JSON error: {}", e))?;
 
-    Ok(data["balance"].as_u64().unwrap_or(0))
+    Ok(format_ouro(data["balance"].as_u64().unwrap_or(0)))
+}
+
+/// Fetch the next nonce the node expects from an address
+async fn fetch_nonce(client: &reqwest::Client, node_url: &str, address: &str) -> Result<u64, String> {
+    let url = format!("{}/ouro/nonce/{}", node_url.trim_end_matches('/'), address);
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch nonce: HTTP {}", response.status()));
+    }
+
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("JSON error: {}", e))?;
+
+    data["nonce"]
+        .as_u64()
+        .ok_or("Nonce missing from node response".to_string())
 }
 
 /// Send transaction
@@ -177,34 +312,33 @@ pub async fn get_microchain_balance(
 pub async fn send_transaction(
     node_url: String,
     to: String,
-    amount: u64,
+    amount: String,
+    fee: Option<u64>,
+    passphrase: Option<String>,
     app: AppHandle,
+    session: State<'_, WalletSession>,
 ) -> Result<String, String> {
-    let wallet_path = get_wallet_path(&app);
-    let wallet = Wallet::load(&wallet_path)?;
-
-    // Create transaction
-    let tx_id = uuid::Uuid::new_v4().to_string();
-    let nonce = 1; // In production, get from node
-    let message = format!("{}:{}:{}:{}:{}", tx_id, wallet.address, to, amount, nonce);
-    let signature = wallet.sign(message.as_bytes())?;
+    let amount = parse_amount(&amount)?;
+    let wallet = signing_wallet(&app, &session, passphrase).await?;
+    let client = reqwest::Client::new();
 
-    let tx_data = serde_json::json!({
-        "id": tx_id,
-        "from": wallet.address,
-        "to": to,
-        "amount": amount,
-        "nonce": nonce,
-        "signature": signature,
-        "timestamp": chrono::Utc::now().to_rfc3339(),
-    });
+    // Sign the canonical message with the nonce the node expects next
+    let nonce = fetch_nonce(&client, &node_url, &wallet.address).await?;
+    let mut tx = Transaction::new(
+        wallet.address.clone(),
+        to,
+        amount,
+        fee.unwrap_or(DEFAULT_FEE),
+        nonce,
+        wallet.public_key.clone(),
+    );
+    tx.sign(&wallet.signing_key()?);
 
-    let client = reqwest::Client::new();
     let url = format!("{}/tx/submit", node_url.trim_end_matches('/'));
 
     let response = client
         .post(&url)
-        .json(&tx_data)
+        .json(&tx.to_api_format())
         .send()
         .await
         .map_err(|e| format!("Network error: {}", e))?;
@@ -215,7 +349,7 @@ pub async fn send_transaction(
         .map_err(|e| format!("JSON error: {}", e))?;
 
     if data["success"].as_bool().unwrap_or(false) {
-        Ok(data["tx_id"].as_str().unwrap_or(&tx_id).to_string())
+        Ok(data["tx_id"].as_str().unwrap_or(&tx.id).to_string())
     } else {
         Err(data["message"]
             .as_str()
@@ -230,28 +364,20 @@ pub async fn send_microchain_transaction(
     node_url: String,
     microchain_id: String,
     to: String,
-    amount: u64,
+    amount: String,
+    passphrase: Option<String>,
     app: AppHandle,
+    session: State<'_, WalletSession>,
 ) -> Result<String, String> {
-    let wallet_path = get_wallet_path(&app);
-    let wallet = Wallet::load(&wallet_path)?;
+    let amount = parse_amount(&amount)?;
+    let wallet = signing_wallet(&app, &session, passphrase).await?;
 
-    let tx_id = uuid::Uuid::new_v4().to_string();
-    let nonce = 1;
-    let message = format!("{}:{}:{}:{}:{}", tx_id, wallet.address, to, amount, nonce);
-    let signature = wallet.sign(message.as_bytes())?;
+    let client = reqwest::Client::new();
 
-    let tx_data = serde_json::json!({
-        "id": tx_id,
-        "from": wallet.address,
-        "to": to,
-        "amount": amount,
-        "nonce": nonce,
-        "signature": signature,
-        "timestamp": chrono::Utc::now().to_rfc3339(),
-    });
+    // Same signed shape and nonce source as mainchain transfers
+    let nonce = fetch_nonce(&client, &node_url, &wallet.address).await?;
+    let tx = microchain_transfer(wallet.address.clone(), to, amount, nonce, &wallet.signing_key()?)?;
 
-    let client = reqwest::Client::new();
     let url = format!(
         "{}/microchain/{}/tx",
         node_url.trim_end_matches('/'),
@@ -260,7 +386,7 @@ pub async fn send_microchain_transaction(
 
     let response = client
         .post(&url)
-        .json(&tx_data)
+        .json(&tx)
         .send()
         .await
         .map_err(|e| format!("Network error: {}", e))?;
@@ -271,7 +397,7 @@ pub async fn send_microchain_transaction(
         .map_err(|e| format!("JSON error: {}", e))?;
 
     if data["success"].as_bool().unwrap_or(false) {
-        Ok(data["tx_id"].as_str().unwrap_or(&tx_id).to_string())
+        Ok(data["tx_id"].as_str().unwrap_or(&tx.id).to_string())
     } else {
         Err(data["message"]
             .as_str()
@@ -314,9 +440,13 @@ pub async fn list_microchains(node_url: String) -> Result<Vec<MicrochainInfo>, S
 
 /// Link wallet to node
 #[tauri::command]
-pub async fn link_to_node(node_url: String, app: AppHandle) -> Result<String, String> {
-    let wallet_path = get_wallet_path(&app);
-    let wallet = Wallet::load(&wallet_path)?;
+pub async fn link_to_node(
+    node_url: String,
+    passphrase: Option<String>,
+    app: AppHandle,
+    session: State<'_, WalletSession>,
+) -> Result<String, String> {
+    let wallet = signing_wallet(&app, &session, passphrase).await?;
 
     // Sign linking message
     let message = format!("Link wallet {} to node", wallet.address);
@@ -383,13 +513,17 @@ pub async fn get_transaction_history(
         .as_array()
         .unwrap_or(&vec![])
         .iter()
-        .map(|tx| TransactionRecord {
-            id: tx["id"].as_str().unwrap_or("").to_string(),
-            from: tx["from"].as_str().unwrap_or("").to_string(),
-            to: tx["to"].as_str().unwrap_or("").to_string(),
-            amount: tx["amount"].as_u64().unwrap_or(0),
-            timestamp: tx["timestamp"].as_str().unwrap_or("").to_string(),
-            status: tx["status"].as_str().unwrap_or("pending").to_string(),
+        .map(|tx| {
+            let amount = tx["amount"].as_u64().unwrap_or(0);
+            TransactionRecord {
+                id: tx["id"].as_str().unwrap_or("").to_string(),
+                from: tx["from"].as_str().unwrap_or("").to_string(),
+                to: tx["to"].as_str().unwrap_or("").to_string(),
+                amount,
+                amount_ouro: format_ouro(amount),
+                timestamp: tx["timestamp"].as_str().unwrap_or("").to_string(),
+                status: tx["status"].as_str().unwrap_or("pending").to_string(),
+            }
         })
         .collect();
 
@@ -5,11 +5,14 @@
 
 mod wallet;
 mod commands;
+mod transaction;
+mod units;
 
 use tauri::Manager;
 
 fn main() {
     tauri::Builder::default()
+        .manage(commands::WalletSession::default())
         .setup(|app| {
             // Initialize wallet storage on startup
             let app_dir = app.path_resolver()
@@ -25,6 +28,7 @@ fn main() {
             commands::import_wallet,
             commands::import_from_key,
             commands::get_wallet_info,
+            commands::unlock_wallet,
             commands::export_mnemonic,
             commands::get_balance,
             commands::get_microchain_balance,
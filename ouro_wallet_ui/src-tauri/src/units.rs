@@ -0,0 +1,29 @@
+//! OURO amount conversions for the UI, backed by `ouro_sdk::units`
+//!
+//! Amounts cross the JS boundary as strings: a JS number can't hold more than
+//! 2^53 smallest units (about 9007 OURO) exactly.
+
+pub use ouro_sdk::units::format_ouro;
+
+/// Parse an amount typed in the UI (in OURO) into smallest units
+pub fn parse_amount(amount: &str) -> Result<u64, String> {
+    ouro_sdk::units::parse_ouro(amount).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same fixture the SDK and midgard-wallet test against
+    const UNITS_CASES: &str = include_str!("../../../ouro_sdk/testdata/units_cases.json");
+
+    #[test]
+    fn test_amounts_match_shared_table() {
+        let cases: Vec<(String, u64)> = serde_json::from_str(UNITS_CASES).unwrap();
+        for (human, units) in cases {
+            assert_eq!(parse_amount(&human).unwrap(), units);
+            assert_eq!(format_ouro(units), human);
+        }
+        assert!(parse_amount("0.0000000000001").is_err());
+    }
+}
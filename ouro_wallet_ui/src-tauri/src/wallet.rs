@@ -1,104 +1,130 @@
-use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
-use bip39::{Mnemonic, Language};
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use bech32::{Bech32, Hrp};
+use bip39::{Language, Mnemonic};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey, SECRET_KEY_LENGTH};
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::fs;
 
+/// scrypt cost (N = 2^17, r = 8, p = 1) used when saving a wallet
+const SCRYPT_LOG_N: u8 = 17;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Private key sealed with a passphrase (scrypt + AES-256-GCM)
+///
+/// The address is used as associated data, so a sealed key can't be moved
+/// into another wallet file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptedKey {
+    pub kdf_log_n: u8,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Wallet {
     pub address: String,
     pub public_key: String,
+    /// Decrypted key; never written to disk
     #[serde(skip_serializing)]
     pub private_key: Option<String>,
     pub name: Option<String>,
     pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_key: Option<EncryptedKey>,
 }
 
 impl Wallet {
     /// Generate a new wallet with mnemonic
     pub fn generate(name: Option<String>) -> (Self, String) {
-        let mnemonic = Mnemonic::new(bip39::MnemonicType::Words12, Language::English);
-        let seed = mnemonic.phrase();
+        let mut entropy = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut entropy);
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
+            .expect("16 bytes is valid mnemonic entropy");
+        let phrase = mnemonic.to_string();
 
-        let wallet = Self::from_mnemonic(seed, name).expect("Failed to create wallet from mnemonic");
-        (wallet, seed.to_string())
+        let wallet = Self::from_mnemonic(&phrase, name).expect("Failed to create wallet from mnemonic");
+        (wallet, phrase)
     }
 
     /// Import wallet from mnemonic phrase
+    ///
+    /// Uses the first 32 bytes of the BIP39 seed, like midgard-wallet, so the
+    /// same phrase yields the same address in both wallets.
     pub fn from_mnemonic(mnemonic: &str, name: Option<String>) -> Result<Self, String> {
-        let mnemonic = Mnemonic::from_phrase(mnemonic, Language::English)
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, mnemonic)
             .map_err(|e| format!("Invalid mnemonic: {}", e))?;
 
-        let seed = mnemonic.entropy();
-        let mut seed_32 = [0u8; 32];
-        seed_32.copy_from_slice(&seed[..32.min(seed.len())]);
+        let seed = mnemonic.to_seed("");
+        let mut secret = [0u8; SECRET_KEY_LENGTH];
+        secret.copy_from_slice(&seed[..SECRET_KEY_LENGTH]);
 
-        let secret = SecretKey::from_bytes(&seed_32)
-            .map_err(|e| format!("Failed to create secret key: {}", e))?;
-        let public = PublicKey::from(&secret);
-        let keypair = Keypair { secret, public };
-
-        let address = Self::encode_address(&keypair.public);
-
-        Ok(Self {
-            address,
-            public_key: hex::encode(keypair.public.as_bytes()),
-            private_key: Some(hex::encode(keypair.to_bytes())),
-            name,
-            created_at: chrono::Utc::now().to_rfc3339(),
-        })
+        Ok(Self::from_signing_key(SigningKey::from_bytes(&secret), name))
     }
 
     /// Import wallet from private key
     pub fn from_private_key(private_key_hex: &str, name: Option<String>) -> Result<Self, String> {
-        let private_bytes = hex::decode(private_key_hex)
-            .map_err(|e| format!("Invalid hex: {}", e))?;
-
-        if private_bytes.len() != 64 {
-            return Err("Private key must be 64 bytes".to_string());
-        }
+        let private_bytes: [u8; 64] = hex::decode(private_key_hex)
+            .map_err(|e| format!("Invalid hex: {}", e))?
+            .try_into()
+            .map_err(|_| "Private key must be 64 bytes".to_string())?;
 
-        let secret = SecretKey::from_bytes(&private_bytes[..32])
+        let signing_key = SigningKey::from_keypair_bytes(&private_bytes)
             .map_err(|e| format!("Invalid secret key: {}", e))?;
-        let public = PublicKey::from(&secret);
-        let keypair = Keypair { secret, public };
 
-        let address = Self::encode_address(&keypair.public);
+        Ok(Self::from_signing_key(signing_key, name))
+    }
 
-        Ok(Self {
-            address,
-            public_key: hex::encode(keypair.public.as_bytes()),
-            private_key: Some(hex::encode(keypair.to_bytes())),
+    fn from_signing_key(signing_key: SigningKey, name: Option<String>) -> Self {
+        let verifying_key = signing_key.verifying_key();
+
+        Self {
+            address: Self::encode_address(&verifying_key),
+            public_key: hex::encode(verifying_key.as_bytes()),
+            private_key: Some(hex::encode(signing_key.to_keypair_bytes())),
             name,
             created_at: chrono::Utc::now().to_rfc3339(),
-        })
+            encrypted_key: None,
+        }
     }
 
     /// Encode public key to bech32 address
-    fn encode_address(public_key: &PublicKey) -> String {
-        use bech32::{ToBase32, Variant};
+    fn encode_address(public_key: &VerifyingKey) -> String {
         let pub_bytes = public_key.as_bytes();
-        bech32::encode("ouro", pub_bytes.to_base32(), Variant::Bech32)
-            .unwrap_or_else(|_| format!("ouro1{}", hex::encode(&pub_bytes[..20])))
+        Hrp::parse("ouro")
+            .ok()
+            .and_then(|hrp| bech32::encode::<Bech32>(hrp, pub_bytes).ok())
+            .unwrap_or_else(|| format!("ouro1{}", hex::encode(&pub_bytes[..20])))
     }
 
-    /// Sign a message
-    pub fn sign(&self, message: &[u8]) -> Result<String, String> {
+    /// Get the signing key
+    pub fn signing_key(&self) -> Result<SigningKey, String> {
         let private_key = self.private_key.as_ref()
             .ok_or("No private key available")?;
 
-        let private_bytes = hex::decode(private_key)
-            .map_err(|e| format!("Invalid private key: {}", e))?;
+        let private_bytes: [u8; 64] = hex::decode(private_key)
+            .map_err(|e| format!("Invalid private key: {}", e))?
+            .try_into()
+            .map_err(|_| "Private key must be 64 bytes".to_string())?;
 
-        let keypair = Keypair::from_bytes(&private_bytes)
-            .map_err(|e| format!("Invalid keypair: {}", e))?;
+        SigningKey::from_keypair_bytes(&private_bytes)
+            .map_err(|e| format!("Invalid keypair: {}", e))
+    }
 
-        let signature = keypair.sign(message);
+    /// Sign a message
+    pub fn sign(&self, message: &[u8]) -> Result<String, String> {
+        let signature = self.signing_key()?.sign(message);
         Ok(hex::encode(signature.to_bytes()))
     }
 
-    /// Save wallet to file
-    pub fn save(&self, path: &PathBuf) -> Result<(), String> {
+    /// Encrypt the private key with a passphrase and save the wallet to file
+    pub fn save(&mut self, path: &PathBuf, passphrase: &str) -> Result<(), String> {
+        self.encrypted_key = Some(self.seal(passphrase, SCRYPT_LOG_N)?);
+
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| format!("Serialization error: {}", e))?;
 
@@ -108,7 +134,7 @@ impl Wallet {
         Ok(())
     }
 
-    /// Load wallet from file
+    /// Load wallet from file (locked until `unlock` is called)
     pub fn load(path: &PathBuf) -> Result<Self, String> {
         let json = fs::read_to_string(path)
             .map_err(|e| format!("File read error: {}", e))?;
@@ -116,6 +142,63 @@ impl Wallet {
         serde_json::from_str(&json)
             .map_err(|e| format!("Deserialization error: {}", e))
     }
+
+    /// Decrypt the private key with the wallet passphrase
+    pub fn unlock(&mut self, passphrase: &str) -> Result<(), String> {
+        let sealed = self.encrypted_key.as_ref()
+            .ok_or("Wallet has no stored key. Please re-import it.")?;
+
+        // An edited file could otherwise demand an arbitrarily expensive scrypt
+        if sealed.kdf_log_n > SCRYPT_LOG_N {
+            return Err("Corrupt wallet file: unsupported scrypt cost".to_string());
+        }
+
+        let decode = |field: &str| hex::decode(field).map_err(|_| "Corrupt wallet file".to_string());
+        let (salt, nonce, ciphertext) = (decode(&sealed.salt)?, decode(&sealed.nonce)?, decode(&sealed.ciphertext)?);
+        if nonce.len() != 12 {
+            return Err("Corrupt wallet file".to_string());
+        }
+
+        let cipher = Self::cipher(passphrase, &salt, sealed.kdf_log_n)?;
+        let private_key = cipher
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: self.address.as_bytes() })
+            .map_err(|_| "Incorrect passphrase".to_string())?;
+
+        self.private_key = Some(hex::encode(private_key));
+        Ok(())
+    }
+
+    fn seal(&self, passphrase: &str, log_n: u8) -> Result<EncryptedKey, String> {
+        let private_key = hex::decode(self.private_key.as_ref().ok_or("No private key available")?)
+            .map_err(|e| format!("Invalid private key: {}", e))?;
+
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let ciphertext = Self::cipher(passphrase, &salt, log_n)?
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &private_key, aad: self.address.as_bytes() })
+            .map_err(|e| format!("Encryption error: {}", e))?;
+
+        Ok(EncryptedKey {
+            kdf_log_n: log_n,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    fn cipher(passphrase: &str, salt: &[u8], log_n: u8) -> Result<Aes256Gcm, String> {
+        let params = scrypt::Params::new(log_n, SCRYPT_R, SCRYPT_P, 32)
+            .map_err(|e| format!("Invalid scrypt parameters: {}", e))?;
+
+        let mut key = [0u8; 32];
+        scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+            .map_err(|e| format!("Key derivation error: {}", e))?;
+
+        Aes256Gcm::new_from_slice(&key).map_err(|e| format!("Cipher error: {}", e))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -136,3 +219,57 @@ impl From<Wallet> for WalletInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap scrypt cost so tests stay fast
+    const TEST_LOG_N: u8 = 10;
+
+    fn sealed_wallet(passphrase: &str) -> Wallet {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut wallet = Wallet::from_private_key(&hex::encode(signing_key.to_keypair_bytes()), None).unwrap();
+        wallet.encrypted_key = Some(wallet.seal(passphrase, TEST_LOG_N).unwrap());
+        wallet
+    }
+
+    /// What the app sees after writing and re-reading the wallet file
+    fn reload(wallet: &Wallet) -> Wallet {
+        serde_json::from_str(&serde_json::to_string(wallet).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_encrypted_wallet_round_trip() {
+        let wallet = sealed_wallet("correct horse");
+        let json = serde_json::to_string(&wallet).unwrap();
+        assert!(!json.contains(wallet.private_key.as_ref().unwrap()));
+
+        let mut loaded = reload(&wallet);
+        assert!(loaded.private_key.is_none());
+        loaded.unlock("correct horse").unwrap();
+        assert_eq!(loaded.private_key, wallet.private_key);
+        assert!(loaded.signing_key().is_ok());
+    }
+
+    #[test]
+    fn test_wrong_passphrase_is_rejected() {
+        let mut loaded = reload(&sealed_wallet("correct horse"));
+        assert_eq!(loaded.unlock("battery staple").unwrap_err(), "Incorrect passphrase");
+        assert!(loaded.private_key.is_none());
+    }
+
+    #[test]
+    fn test_excessive_scrypt_cost_is_rejected() {
+        let mut loaded = reload(&sealed_wallet("correct horse"));
+        loaded.encrypted_key.as_mut().unwrap().kdf_log_n = SCRYPT_LOG_N + 1;
+        assert!(loaded.unlock("correct horse").unwrap_err().contains("scrypt cost"));
+    }
+
+    #[test]
+    fn test_sealed_key_is_bound_to_address() {
+        let mut loaded = reload(&sealed_wallet("correct horse"));
+        loaded.address = "ouro1someoneelse".to_string();
+        assert!(loaded.unlock("correct horse").is_err());
+    }
+}
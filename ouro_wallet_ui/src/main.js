@@ -69,6 +69,10 @@ window.showCreateWallet = function () {
                 <label>Wallet Name (optional)</label>
                 <input type="text" id="wallet-name" placeholder="My Wallet">
             </div>
+            <div class="form-group">
+                <label>Passphrase (encrypts the wallet on this device)</label>
+                <input type="password" id="wallet-passphrase" required>
+            </div>
             <button type="submit" class="btn btn-primary">Create Wallet</button>
         </form>
     `;
@@ -106,6 +110,10 @@ window.showImportFromMnemonic = function () {
                 <label>Wallet Name (optional)</label>
                 <input type="text" id="import-name">
             </div>
+            <div class="form-group">
+                <label>Passphrase (encrypts the wallet on this device)</label>
+                <input type="password" id="import-passphrase" required>
+            </div>
             <button type="submit" class="btn btn-primary">Import Wallet</button>
         </form>
     `;
@@ -125,6 +133,10 @@ window.showImportFromKey = function () {
                 <label>Wallet Name (optional)</label>
                 <input type="text" id="import-key-name">
             </div>
+            <div class="form-group">
+                <label>Passphrase (encrypts the wallet on this device)</label>
+                <input type="password" id="import-key-passphrase" required>
+            </div>
             <button type="submit" class="btn btn-primary">Import Wallet</button>
         </form>
     `;
@@ -134,9 +146,10 @@ window.showImportFromKey = function () {
 window.createWallet = async function (event) {
     event.preventDefault();
     const name = document.getElementById('wallet-name').value || null;
+    const passphrase = document.getElementById('wallet-passphrase').value;
 
     try {
-        const result = await invoke('create_wallet', { name });
+        const result = await invoke('create_wallet', { name, passphrase });
         wallet = result.wallet;
 
         // Show mnemonic
@@ -166,9 +179,10 @@ window.importFromMnemonic = async function (event) {
     event.preventDefault();
     const mnemonic = document.getElementById('import-mnemonic').value.trim();
     const name = document.getElementById('import-name').value || null;
+    const passphrase = document.getElementById('import-passphrase').value;
 
     try {
-        wallet = await invoke('import_wallet', { mnemonic, name });
+        wallet = await invoke('import_wallet', { mnemonic, name, passphrase });
         finishWalletSetup();
     } catch (error) {
         alert('Error importing wallet: ' + error);
@@ -179,9 +193,10 @@ window.importFromKey = async function (event) {
     event.preventDefault();
     const privateKey = document.getElementById('import-key').value.trim();
     const name = document.getElementById('import-key-name').value || null;
+    const passphrase = document.getElementById('import-key-passphrase').value;
 
     try {
-        wallet = await invoke('import_from_key', { privateKey, name });
+        wallet = await invoke('import_from_key', { privateKey, name, passphrase });
         finishWalletSetup();
     } catch (error) {
         alert('Error importing wallet: ' + error);
@@ -217,9 +232,9 @@ async function loadDashboardData() {
 window.refreshBalance = async function () {
     try {
         const balance = await invoke('get_balance', { nodeUrl });
-        document.getElementById('main-balance').textContent = (balance.balance / 100).toFixed(2);
+        document.getElementById('main-balance').textContent = balance.balance_ouro;
         document.getElementById('pending-balance').textContent =
-            `Pending: ${(balance.pending / 100).toFixed(2)} OURO`;
+            `Pending: ${balance.pending_ouro} OURO`;
     } catch (error) {
         console.error('Error fetching balance:', error);
         document.getElementById('main-balance').textContent = 'Error';
@@ -255,25 +270,77 @@ async function loadMicrochainsForSend() {
     }
 }
 
+// Ask for the wallet passphrase in a masked field; resolves to null if the modal is closed
+let passphraseRequest = null;
+
+function askPassphrase() {
+    const modalBody = document.getElementById('modal-body');
+    modalBody.innerHTML = `
+        <h2 class="mb-16">Unlock Wallet</h2>
+        <form onsubmit="submitPassphrase(event)">
+            <div class="form-group">
+                <label>Passphrase</label>
+                <input type="password" id="unlock-passphrase" required>
+            </div>
+            <button type="submit" class="btn btn-primary">Unlock</button>
+        </form>
+    `;
+    showModal();
+    document.getElementById('unlock-passphrase').focus();
+
+    return new Promise((resolve) => {
+        passphraseRequest = resolve;
+    });
+}
+
+window.submitPassphrase = function (event) {
+    event.preventDefault();
+    const passphrase = document.getElementById('unlock-passphrase').value;
+    const resolve = passphraseRequest;
+    passphraseRequest = null;
+    closeModal();
+    resolve(passphrase);
+};
+
+// Run a command that needs the decrypted key, unlocking the wallet first if the session expired
+async function withUnlockedWallet(command) {
+    try {
+        return await command();
+    } catch (error) {
+        if (!String(error).startsWith('Wallet is locked')) {
+            throw error;
+        }
+        const passphrase = await askPassphrase();
+        if (!passphrase) {
+            throw error;
+        }
+        await invoke('unlock_wallet', { passphrase });
+        return await command();
+    }
+}
+
 window.sendTransaction = async function (event) {
     event.preventDefault();
 
     const chain = document.getElementById('send-chain').value;
     const to = document.getElementById('send-to').value;
-    const amount = Math.floor(parseFloat(document.getElementById('send-amount').value) * 100);
+    // Sent as a string; the backend converts OURO to units with ouro_sdk::units
+    const amount = document.getElementById('send-amount').value.trim();
 
     try {
         let txId;
         if (chain === 'mainchain') {
-            txId = await invoke('send_transaction', { nodeUrl, to, amount });
+            txId = await withUnlockedWallet(() => invoke('send_transaction', { nodeUrl, to, amount }));
         } else {
             const microchainId = document.getElementById('send-microchain-id').value;
-            txId = await invoke('send_microchain_transaction', {
-                nodeUrl,
-                microchainId,
-                to,
-                amount,
-            });
+            txId = await withUnlockedWallet(() =>
+                invoke('send_microchain_transaction', {
+                    nodeUrl,
+                    microchainId,
+                    to,
+                    amount,
+                })
+            );
         }
 
         alert(`Transaction sent! TX ID: ${txId}`);
@@ -320,7 +387,7 @@ window.refreshMicrochains = async function () {
 window.viewMicrochainBalance = async function (microchainId) {
     try {
         const balance = await invoke('get_microchain_balance', { nodeUrl, microchainId });
-        alert(`Balance: ${(balance / 100).toFixed(2)} tokens`);
+        alert(`Balance: ${balance} tokens`);
     } catch (error) {
         alert('Error fetching balance: ' + error);
     }
@@ -351,7 +418,7 @@ window.refreshHistory = async function () {
                 </div>
                 <div class="history-item-right">
                     <div class="history-item-amount">
-                        ${tx.from === wallet.address ? '-' : '+'}${(tx.amount / 100).toFixed(2)} OURO
+                        ${tx.from === wallet.address ? '-' : '+'}${tx.amount_ouro} OURO
                     </div>
                     <div class="history-item-status status-${tx.status}">
                         ${tx.status}
@@ -369,7 +436,7 @@ window.refreshHistory = async function () {
 // Node Linking
 window.linkToNode = async function () {
     try {
-        const result = await invoke('link_to_node', { nodeUrl });
+        const result = await withUnlockedWallet(() => invoke('link_to_node', { nodeUrl }));
         alert(result);
     } catch (error) {
         alert('Linking failed: ' + error);
@@ -388,9 +455,13 @@ window.exportMnemonic = async function () {
     if (!confirm('Are you sure you want to export your recovery phrase?')) {
         return;
     }
+    const passphrase = await askPassphrase();
+    if (!passphrase) {
+        return;
+    }
 
     try {
-        const mnemonic = await invoke('export_mnemonic');
+        const mnemonic = await invoke('export_mnemonic', { passphrase });
         const modalBody = document.getElementById('modal-body');
         modalBody.innerHTML = `
             <h2 class="mb-16">Recovery Phrase</h2>
@@ -428,4 +499,8 @@ function showModal() {
 
 window.closeModal = function () {
     document.getElementById('modal').style.display = 'none';
+    if (passphraseRequest) {
+        passphraseRequest(null);
+        passphraseRequest = null;
+    }
 };